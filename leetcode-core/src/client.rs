@@ -0,0 +1,203 @@
+use crate::build_reqwest_client;
+use crate::errors::AppResult;
+use crate::graphql::cache::DiskCache;
+use crate::graphql::cassette::Cassette;
+use crate::graphql::client::hash_string;
+use crate::network::NetworkOptions;
+use crate::site::Host;
+use lru::LruCache;
+use reqwest::Client;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, RwLock};
+
+pub(crate) type MemoryCache = RwLock<LruCache<u64, String>>;
+
+pub(crate) fn new_memory_cache() -> Arc<MemoryCache> {
+    Arc::new(RwLock::new(LruCache::new(NonZeroUsize::new(20).unwrap())))
+}
+
+/// Cookies, site and caches a request is sent with, see
+/// [`GQLLeetcodeRequest::send_with`](crate::GQLLeetcodeRequest::send_with).
+///
+/// Clones share the connection pool and the caches. Every client built with
+/// [`LeetcodeClient::new`] starts with a cache of its own, so several accounts
+/// can be used side by side. Queries that differ between leetcode.com and
+/// leetcode.cn pick their flavour from the site of the client sending them, so
+/// clients of both sites can be used at once.
+#[derive(Clone)]
+pub struct LeetcodeClient {
+    http: Client,
+    /// Hash of the session cookie, tells the cached responses of accounts apart
+    session_key: u64,
+    host: Host,
+    network: NetworkOptions,
+    memory_cache: Arc<MemoryCache>,
+    disk_cache: Option<Arc<DiskCache>>,
+    cassette: Option<Arc<Cassette>>,
+}
+
+impl LeetcodeClient {
+    pub async fn new(
+        csrf: &str,
+        sess: &str,
+        host: Host,
+        network: NetworkOptions,
+    ) -> AppResult<Self> {
+        let http = build_reqwest_client(csrf, sess, &host, &network).await?;
+        Ok(Self {
+            http,
+            session_key: hash_string(sess),
+            host,
+            network,
+            memory_cache: new_memory_cache(),
+            disk_cache: None,
+            cassette: None,
+        })
+    }
+
+    /// Responses with a ttl are also kept on disk, see [`DiskCache`].
+    pub fn with_disk_cache(mut self, disk_cache: Arc<DiskCache>) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
+    /// Records the traffic to the cassette or serves it from there, see [`Cassette`].
+    pub fn with_cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    pub(crate) fn with_memory_cache(mut self, memory_cache: Arc<MemoryCache>) -> Self {
+        self.memory_cache = memory_cache;
+        self
+    }
+
+    /// Swaps the cookies, the caches are kept.
+    pub async fn set_credentials(&mut self, csrf: &str, sess: &str) -> AppResult<()> {
+        self.http = build_reqwest_client(csrf, sess, &self.host, &self.network).await?;
+        self.session_key = hash_string(sess);
+        Ok(())
+    }
+
+    pub fn host(&self) -> &Host {
+        &self.host
+    }
+
+    pub(crate) fn session_key(&self) -> u64 {
+        self.session_key
+    }
+
+    pub(crate) fn http(&self) -> &Client {
+        &self.http
+    }
+
+    pub(crate) fn memory_cache(&self) -> &MemoryCache {
+        &self.memory_cache
+    }
+
+    pub(crate) fn disk_cache(&self) -> Option<&DiskCache> {
+        self.disk_cache.as_deref()
+    }
+
+    pub(crate) fn cassette(&self) -> Option<&Cassette> {
+        self.cassette.as_deref()
+    }
+
+    /// Drops every cached response, both in memory and on disk.
+    pub fn clear_cache(&self) -> std::io::Result<()> {
+        self.memory_cache.write().unwrap().clear();
+        if let Some(disk_cache) = self.disk_cache() {
+            disk_cache.clear()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GQLLeetcodeRequest, Site};
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct CachedQuery {
+        query: &'static str,
+    }
+
+    impl GQLLeetcodeRequest for CachedQuery {
+        type T = serde_json::Value;
+
+        fn use_cache(&self) -> bool {
+            true
+        }
+    }
+
+    async fn unreachable_client() -> LeetcodeClient {
+        LeetcodeClient::new(
+            "csrf",
+            "session",
+            Host::new(Site::Com, Some("http://127.0.0.1:9")),
+            NetworkOptions::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_clients_do_not_share_caches() {
+        let query = CachedQuery { query: "query" };
+        let first = unreachable_client().await;
+        let second = unreachable_client().await;
+        first
+            .memory_cache()
+            .write()
+            .unwrap()
+            .put(query.get_query_hash(&first), r#"{"data": 1}"#.into());
+
+        let response = query.send_with(&first).await.unwrap();
+        assert_eq!(response["data"], 1);
+        // clones share the cache, separately built clients do not
+        assert_eq!(first.clone().memory_cache().read().unwrap().len(), 1);
+        assert!(second.memory_cache().read().unwrap().is_empty());
+
+        query.invalidate_cache_with(&first).unwrap();
+        assert!(first.memory_cache().read().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cache_keys_differ_per_session() {
+        let query = CachedQuery { query: "query" };
+        let mut client = unreachable_client().await;
+        let key = query.get_query_hash(&client);
+        assert_eq!(key, query.get_query_hash(&client.clone()));
+
+        client
+            .set_credentials("csrf", "other session")
+            .await
+            .unwrap();
+        assert_ne!(key, query.get_query_hash(&client));
+    }
+
+    #[tokio::test]
+    async fn test_requests_follow_the_host_of_the_client() {
+        let query = crate::SubmissionListRequest::new("two-sum".into());
+        let com = unreachable_client().await;
+        let cn = LeetcodeClient::new(
+            "csrf",
+            "session",
+            Host::new(Site::Cn, Some("http://127.0.0.1:9")),
+            NetworkOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_ne!(query.get_query_hash(&com), query.get_query_hash(&cn));
+        assert_ne!(
+            query.get_body(com.host().site())["query"],
+            query.get_body(cn.host().site())["query"]
+        );
+        assert_eq!(
+            query.get_body(Site::Cn)["variables"]["questionSlug"],
+            "two-sum"
+        );
+    }
+}
@@ -11,14 +11,55 @@ pub enum LcAppError {
     #[error("Network request error.")]
     RequestError(#[from] reqwest::Error),
 
+    #[error("Too many requests, leetcode is rate limiting. Retry after {retry_after:?}")]
+    RateLimitError {
+        retry_after: Option<std::time::Duration>,
+    },
+
+    #[error("Leetcode server error {code:?}: {contents:?}")]
+    ServerError { code: String, contents: String },
+
     #[error("Status {code:?}: {contents:?}")]
     StatusCodeError { code: String, contents: String },
 
     #[error("Error while building reqwest client: {0}")]
     ClientBuildError(#[from] reqwest::header::InvalidHeaderValue),
 
+    #[error("Invalid network settings, {0}")]
+    NetworkConfigError(String),
+
+    #[error("Cassette {0}")]
+    CassetteError(String),
+
+    #[error("No recorded response in the cassette for {0}")]
+    CassetteMiss(String),
+
+    #[error("Judge did not return the result within {0:?}")]
+    PollTimeoutError(std::time::Duration),
+
+    #[error("Stopped waiting for the judge result")]
+    PollCancelledError,
+
+    #[error("Question not found: {0}")]
+    QuestionNotFound(String),
+
+    #[error("Only available with leetcode premium: {0}")]
+    PremiumRequired(String),
+
+    #[error("Not signed in: {0}")]
+    Unauthenticated(String),
+
+    #[error("Leetcode rejected the query: {0}")]
+    GraphQLError(String),
+
+    #[error("Language list file {0}")]
+    LanguageListFileError(String),
+
     #[error("Language does not exist for question {0}")]
     LanguageDoesNotExistError(String),
+
+    #[error("No favorite list named \"{0}\" on leetcode")]
+    FavoriteListNotFoundError(String),
 }
 
 pub type AppResult<T> = Result<T, LcAppError>;
@@ -0,0 +1,250 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+
+/// Disk cache is opt-in, requests only hit the in-memory cache until [`init`] is called.
+pub static DISK_CACHE: OnceLock<Arc<DiskCache>> = OnceLock::new();
+
+/// Attaches the cache to the default client, whichever of the two is set up first.
+pub fn init(dir: &Path, max_size_bytes: u64) -> std::io::Result<()> {
+    let cache = Arc::new(DiskCache::new(dir, max_size_bytes)?);
+    let cache = DISK_CACHE.get_or_init(|| cache);
+    if let Some(client) = crate::REQ_CLIENT.get() {
+        let mut client = client.write().unwrap();
+        *client = client.clone().with_disk_cache(cache.clone());
+    }
+    Ok(())
+}
+
+/// Tells apart the temp files of concurrent writes.
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Responses stored one file per query hash. The modification time of the file
+/// is the time the response was fetched, an entry is fresh while it is younger
+/// than the ttl of the request that reads it.
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_size_bytes: u64,
+    /// Bytes taken by the entries, read from the directory once at startup
+    size: Mutex<u64>,
+}
+
+impl DiskCache {
+    pub fn new(dir: &Path, max_size_bytes: u64) -> std::io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let mut cache = Self {
+            dir: dir.to_path_buf(),
+            max_size_bytes,
+            size: Mutex::new(0),
+        };
+        let size = cache.entries()?.iter().map(|(_, size, _)| size).sum();
+        *cache.size.get_mut().unwrap() = size;
+        Ok(cache)
+    }
+
+    fn entry_path(&self, key: u64) -> PathBuf {
+        self.dir.join(format!("{key:016x}.json"))
+    }
+
+    /// Returns the entry only if it is younger than `ttl`.
+    pub fn get(&self, key: u64, ttl: Duration) -> Option<String> {
+        let path = self.entry_path(key);
+        let age = fs::metadata(&path).ok()?.modified().ok()?.elapsed().ok()?;
+        if age >= ttl {
+            return None;
+        }
+        fs::read_to_string(path).ok()
+    }
+
+    /// Returns the entry regardless of its age, used when the network is unreachable.
+    pub fn get_stale(&self, key: u64) -> Option<String> {
+        fs::read_to_string(self.entry_path(key)).ok()
+    }
+
+    pub fn put(&self, key: u64, value: &str) -> std::io::Result<()> {
+        if value.len() as u64 > self.max_size_bytes {
+            return Ok(());
+        }
+        // write then rename so that a reader never sees a half written entry,
+        // every write gets its own temp file as the same key may be put concurrently
+        let tmp_path = self.dir.join(format!(
+            "{key:016x}.{}.{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = fs::File::create(&tmp_path).and_then(|mut f| f.write_all(value.as_bytes()));
+        if let Err(e) = written {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e);
+        }
+        let path = self.entry_path(key);
+
+        // the replaced size is read under the lock so that it is only subtracted once
+        let mut size = self.size.lock().unwrap();
+        let replaced = fs::metadata(&path).map(|m| m.len()).unwrap_or_default();
+        if let Err(e) = fs::rename(&tmp_path, path) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e);
+        }
+        *size = size.saturating_sub(replaced) + value.len() as u64;
+        if *size > self.max_size_bytes {
+            *size = self.evict(key)?;
+        }
+        Ok(())
+    }
+
+    pub fn invalidate(&self, key: u64) -> std::io::Result<()> {
+        let path = self.entry_path(key);
+        let mut size = self.size.lock().unwrap();
+        let removed = fs::metadata(&path).map(|m| m.len()).unwrap_or_default();
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+            _ => (),
+        }
+        *size = size.saturating_sub(removed);
+        Ok(())
+    }
+
+    pub fn clear(&self) -> std::io::Result<()> {
+        let mut size = self.size.lock().unwrap();
+        for (path, ..) in self.entries()? {
+            fs::remove_file(path)?;
+        }
+        *size = 0;
+        Ok(())
+    }
+
+    pub fn size(&self) -> u64 {
+        *self.size.lock().unwrap()
+    }
+
+    fn entries(&self) -> std::io::Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let mut entries = vec![];
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            entries.push((path, metadata.len(), metadata.modified()?));
+        }
+        Ok(entries)
+    }
+
+    /// Removes the least recently fetched entries until the cache fits in the
+    /// size limit, returns the size left. Only scans the directory once the
+    /// tracked size went over the limit.
+    fn evict(&self, keep: u64) -> std::io::Result<u64> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum();
+        if total <= self.max_size_bytes {
+            return Ok(total);
+        }
+        let keep = self.entry_path(keep);
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        for (path, size, _) in entries {
+            if total <= self.max_size_bytes {
+                break;
+            }
+            if path == keep {
+                continue;
+            }
+            fs::remove_file(path)?;
+            total -= size;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_in(name: &str, max_size_bytes: u64) -> DiskCache {
+        let dir = std::env::temp_dir().join(format!("leetcode-core-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        DiskCache::new(&dir, max_size_bytes).unwrap()
+    }
+
+    #[test]
+    fn test_entry_is_returned_until_invalidated() {
+        let cache = cache_in("invalidate", 1024);
+        cache.put(1, "{\"a\": 1}").unwrap();
+        assert_eq!(
+            cache.get(1, Duration::from_secs(60)).as_deref(),
+            Some("{\"a\": 1}")
+        );
+        cache.invalidate(1).unwrap();
+        assert!(cache.get(1, Duration::from_secs(60)).is_none());
+        // invalidating a missing entry is not an error
+        cache.invalidate(1).unwrap();
+    }
+
+    #[test]
+    fn test_expired_entry_is_only_served_stale() {
+        let cache = cache_in("ttl", 1024);
+        cache.put(2, "{}").unwrap();
+        assert!(cache.get(2, Duration::ZERO).is_none());
+        assert_eq!(cache.get_stale(2).as_deref(), Some("{}"));
+    }
+
+    #[test]
+    fn test_cache_is_kept_within_size_limit() {
+        let cache = cache_in("size", 10);
+        cache.put(3, "123456").unwrap();
+        cache.put(4, "123456").unwrap();
+        assert!(cache.size() <= 10);
+        assert!(cache.get_stale(4).is_some());
+
+        // larger than the whole cache, never stored
+        cache.put(5, "12345678901").unwrap();
+        assert!(cache.get_stale(5).is_none());
+
+        cache.clear().unwrap();
+        assert_eq!(cache.size(), 0);
+    }
+
+    #[test]
+    fn test_size_is_tracked_across_writes_and_reopening() {
+        let cache = cache_in("tracked", 1024);
+        cache.put(6, "1234").unwrap();
+        // overwriting an entry replaces its size
+        cache.put(6, "123456").unwrap();
+        cache.put(7, "12").unwrap();
+        assert_eq!(cache.size(), 8);
+        cache.invalidate(7).unwrap();
+        assert_eq!(cache.size(), 6);
+
+        let reopened = DiskCache::new(&cache.dir, 1024).unwrap();
+        assert_eq!(reopened.size(), 6);
+    }
+
+    #[test]
+    fn test_concurrent_puts_of_the_same_key() {
+        let cache = Arc::new(cache_in("concurrent", 1024));
+        let values: Vec<String> = (0..8).map(|i| i.to_string().repeat(32)).collect();
+        let handles: Vec<_> = values
+            .iter()
+            .cloned()
+            .map(|value| {
+                let cache = cache.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..20 {
+                        cache.put(8, &value).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(values.contains(&cache.get_stale(8).unwrap()));
+        assert_eq!(cache.size(), 32);
+        assert_eq!(fs::read_dir(&cache.dir).unwrap().count(), 1);
+    }
+}
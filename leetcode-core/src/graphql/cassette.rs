@@ -0,0 +1,161 @@
+//! Record/replay of the http traffic, for running without network access.
+//!
+//! In record mode every request sent by the client is written to the cassette
+//! file along with the response. In replay mode the responses are served from
+//! the file and nothing is sent. Cookies are not recorded, so a cassette can be
+//! attached to a bug report.
+use crate::errors::{AppResult, LcAppError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Cassette of the default client, see [`init`].
+pub static CASSETTE: OnceLock<Arc<Cassette>> = OnceLock::new();
+
+/// Attaches the cassette to the default client, whichever of the two is set up first.
+pub fn init(path: &Path, mode: CassetteMode) -> AppResult<()> {
+    let cassette = Arc::new(Cassette::open(path, mode)?);
+    let cassette = CASSETTE.get_or_init(|| cassette);
+    if let Some(client) = crate::REQ_CLIENT.get() {
+        let mut client = client.write().unwrap();
+        *client = client.clone().with_cassette(cassette.clone());
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CassetteMode {
+    Record,
+    Replay,
+}
+
+/// One request and what leetcode answered.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    /// Relative to the base url, the cassette works for any host
+    pub path: String,
+    #[serde(default)]
+    pub body: Value,
+    pub status: u16,
+    pub response: String,
+}
+
+impl Interaction {
+    fn matches(&self, method: &str, path: &str, body: &Value) -> bool {
+        self.method == method && self.path == path && &self.body == body
+    }
+}
+
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    interactions: Mutex<Vec<Interaction>>,
+    /// Replay serves identical requests in the recorded order, e.g. the judge
+    /// going from `PENDING` to `SUCCESS`. The last one is repeated once all were served.
+    served: Mutex<Vec<bool>>,
+}
+
+impl Cassette {
+    /// Replay needs an existing file, recording starts from an empty one.
+    pub fn open(path: &Path, mode: CassetteMode) -> AppResult<Self> {
+        let interactions: Vec<Interaction> = match mode {
+            CassetteMode::Replay => {
+                let contents = std::fs::read_to_string(path)
+                    .map_err(|e| LcAppError::CassetteError(format!("{}: {e}", path.display())))?;
+                serde_json::from_str(&contents)?
+            }
+            CassetteMode::Record => vec![],
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            mode,
+            served: Mutex::new(vec![false; interactions.len()]),
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    pub(crate) fn replay(&self, method: &str, path: &str, body: &Value) -> AppResult<Interaction> {
+        let interactions = self.interactions.lock().unwrap();
+        let mut served = self.served.lock().unwrap();
+        let matching: Vec<usize> = interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.matches(method, path, body))
+            .map(|(index, _)| index)
+            .collect();
+        let index = matching
+            .iter()
+            .find(|index| !served[**index])
+            .or(matching.last())
+            .copied()
+            .ok_or_else(|| LcAppError::CassetteMiss(format!("{method} {path}")))?;
+        served[index] = true;
+        Ok(interactions[index].clone())
+    }
+
+    /// The file is rewritten after every interaction so that nothing is lost when
+    /// the app is killed.
+    pub(crate) fn record(&self, interaction: Interaction) -> AppResult<()> {
+        let mut interactions = self.interactions.lock().unwrap();
+        interactions.push(interaction);
+        let contents = serde_json::to_string_pretty(&*interactions)?;
+        let tmp_path = self.path.with_extension("tmp");
+        std::fs::write(&tmp_path, contents)
+            .and_then(|_| std::fs::rename(&tmp_path, &self.path))
+            .map_err(|e| LcAppError::CassetteError(format!("{}: {e}", self.path.display())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn interaction(path: &str, response: &str) -> Interaction {
+        Interaction {
+            method: "GET".into(),
+            path: path.into(),
+            body: Value::Null,
+            status: 200,
+            response: response.into(),
+        }
+    }
+
+    #[test]
+    fn test_recorded_interactions_are_replayed_in_order() {
+        let path = std::env::temp_dir().join(format!(
+            "leetcode-core-cassette-{}.json",
+            std::process::id()
+        ));
+        let recorder = Cassette::open(&path, CassetteMode::Record).unwrap();
+        recorder.record(interaction("/check/", "PENDING")).unwrap();
+        recorder.record(interaction("/check/", "SUCCESS")).unwrap();
+
+        let player = Cassette::open(&path, CassetteMode::Replay).unwrap();
+        let replay = |path: &str| player.replay("GET", path, &Value::Null);
+        assert_eq!(replay("/check/").unwrap().response, "PENDING");
+        assert_eq!(replay("/check/").unwrap().response, "SUCCESS");
+        assert_eq!(replay("/check/").unwrap().response, "SUCCESS");
+        assert!(matches!(
+            replay("/other/"),
+            Err(LcAppError::CassetteMiss(_))
+        ));
+        assert!(matches!(
+            player.replay("POST", "/check/", &json!({})),
+            Err(LcAppError::CassetteMiss(_))
+        ));
+        let _ = std::fs::remove_file(path);
+    }
+}
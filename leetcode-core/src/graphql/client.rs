@@ -1,32 +1,44 @@
+use super::cassette::{CassetteMode, Interaction};
+use super::response::check_errors;
+use super::retry::{get_retry_policy, parse_retry_after};
+use crate::client::{new_memory_cache, MemoryCache};
 use crate::errors::{AppResult, LcAppError};
-use crate::get_client;
+use crate::site::Site;
+use crate::{default_client, LeetcodeClient};
 use async_trait::async_trait;
-use lru::LruCache;
+use reqwest::StatusCode;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::{json, Value};
-use std::collections::hash_map::DefaultHasher;
-use std::hash::Hash;
-use std::hash::Hasher;
-use std::sync::RwLock;
-use std::{num::NonZeroUsize, sync::OnceLock};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
-pub static CACHE: OnceLock<RwLock<LruCache<u64, String>>> = OnceLock::new();
+/// In-memory cache of the default client.
+pub static CACHE: OnceLock<Arc<MemoryCache>> = OnceLock::new();
 
-fn get_cache<'a>() -> &'a RwLock<LruCache<u64, String>> {
-    CACHE.get_or_init(|| RwLock::new(LruCache::new(NonZeroUsize::new(20).unwrap())))
+pub(crate) fn get_cache() -> &'static Arc<MemoryCache> {
+    CACHE.get_or_init(new_memory_cache)
 }
 
-fn hash_string(input: &str) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    input.hash(&mut hasher);
-    hasher.finish()
+/// Drops every response cached by the default client, both in memory and on disk.
+pub fn clear_cache() -> std::io::Result<()> {
+    default_client().clear_cache()
+}
+
+/// 64 bit FNV-1a. The hashes name the disk cache files, so unlike `DefaultHasher`
+/// they must not change between Rust releases.
+pub(crate) fn hash_string(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
 }
 
 #[async_trait]
 pub trait GQLLeetcodeRequest: Serialize + Sync {
     type T: DeserializeOwned;
 
-    fn get_body(&self) -> Value {
+    /// Queries that differ between leetcode.com and leetcode.cn pick their text
+    /// from the site of the client sending them.
+    fn get_body(&self, _site: Site) -> Value {
         json!(self)
     }
 
@@ -34,59 +46,215 @@ pub trait GQLLeetcodeRequest: Serialize + Sync {
         true
     }
 
-    /// Default graphql endpoint
-    fn get_endpoint(&self) -> String {
-        "https://leetcode.com/graphql".to_string()
+    /// Path relative to the configured site. Defaults to the graphql endpoint.
+    fn get_path(&self) -> String {
+        "/graphql".to_string()
     }
 
     fn use_cache(&self) -> bool {
         false
     }
 
-    fn get_query_hash(&self) -> u64 {
-        hash_string(format!("{}{}", self.get_endpoint(), self.get_body()).as_str())
+    /// Whether sending the request twice is harmless. Non idempotent requests are
+    /// only retried when leetcode rejected them for rate limiting.
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    /// How long the response stays valid in the disk cache. `None` keeps it off the disk.
+    fn cache_ttl(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Keyed by the session too, so that switching accounts never serves the
+    /// statuses and favorites cached for another one.
+    fn get_query_hash(&self, client: &LeetcodeClient) -> u64 {
+        hash_string(
+            format!(
+                "{:016x}{}{}",
+                client.session_key(),
+                client.host().url(&self.get_path()),
+                self.get_body(client.host().site())
+            )
+            .as_str(),
+        )
+    }
+
+    /// Forgets the cached response so that the next `send` hits the network.
+    fn invalidate_cache(&self) -> std::io::Result<()> {
+        self.invalidate_cache_with(&default_client())
+    }
+
+    fn invalidate_cache_with(&self, client: &LeetcodeClient) -> std::io::Result<()> {
+        let key = self.get_query_hash(client);
+        client.memory_cache().write().unwrap().pop(&key);
+        if let Some(disk_cache) = client.disk_cache() {
+            disk_cache.invalidate(key)?;
+        }
+        Ok(())
     }
 
+    /// Sends the request with the default client, see [`crate::init`].
     async fn send(&self) -> AppResult<Self::T> {
+        self.send_with(&default_client()).await
+    }
+
+    async fn send_with(&self, client: &LeetcodeClient) -> AppResult<Self::T> {
+        let key = self.get_query_hash(client);
         if self.use_cache() {
-            let mut c = get_cache().write().unwrap();
-            if let Some(value) = c.get(&self.get_query_hash()) {
+            let mut c = client.memory_cache().write().unwrap();
+            if let Some(value) = c.get(&key) {
                 return Ok(serde_json::from_str(value.as_str())?);
             };
         }
 
+        let ttl = self.cache_ttl();
+        let disk_cache = ttl.and(client.disk_cache());
+        if let (Some(disk_cache), Some(ttl)) = (disk_cache, ttl) {
+            if let Some(value) = disk_cache.get(key, ttl) {
+                if let Ok(parsed) = serde_json::from_str(value.as_str()) {
+                    if self.use_cache() {
+                        client.memory_cache().write().unwrap().put(key, value);
+                    }
+                    return Ok(parsed);
+                }
+            }
+        }
+
+        match self.fetch_with(client).await {
+            Ok(result) => {
+                let parsed = match serde_json::from_str(result.as_str()) {
+                    Ok(parsed_message) => parsed_message,
+                    Err(e) => {
+                        log::debug!("{}\n{}", &e, result.as_str());
+                        return Err(LcAppError::DeserializeError(e));
+                    }
+                };
+                if let Some(disk_cache) = disk_cache {
+                    if let Err(e) = disk_cache.put(key, result.as_str()) {
+                        log::debug!("could not write to the disk cache {}", e);
+                    }
+                }
+                if self.use_cache() {
+                    client.memory_cache().write().unwrap().put(key, result);
+                }
+                Ok(parsed)
+            }
+            // serve whatever we have when offline
+            Err(LcAppError::RequestError(e)) => {
+                if let Some(value) = disk_cache.and_then(|c| c.get_stale(key)) {
+                    log::debug!("serving stale cache entry, request failed with {}", e);
+                    return Ok(serde_json::from_str(value.as_str())?);
+                }
+                Err(LcAppError::RequestError(e))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn fetch(&self) -> AppResult<String> {
+        self.fetch_with(&default_client()).await
+    }
+
+    async fn fetch_with(&self, client: &LeetcodeClient) -> AppResult<String> {
+        let policy = get_retry_policy();
+        let mut attempt = 0;
+        loop {
+            match self.fetch_once_with(client).await {
+                Err(e) if policy.should_retry(attempt, &e, self.is_idempotent()) => {
+                    let retry_after = match &e {
+                        LcAppError::RateLimitError { retry_after } => *retry_after,
+                        _ => None,
+                    };
+                    let delay = policy.delay(attempt, retry_after);
+                    log::debug!("{}, retrying in {:?}", e, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn fetch_once(&self) -> AppResult<String> {
+        self.fetch_once_with(&default_client()).await
+    }
+
+    async fn fetch_once_with(&self, client: &LeetcodeClient) -> AppResult<String> {
+        let path = self.get_path();
+        let (method, body) = if self.is_post() {
+            ("POST", self.get_body(client.host().site()))
+        } else {
+            ("GET", Value::Null)
+        };
+        let cassette = client.cassette();
+        if let Some(cassette) = cassette.filter(|c| c.mode() == CassetteMode::Replay) {
+            let interaction = cassette.replay(method, &path, &body)?;
+            let status = StatusCode::from_u16(interaction.status)
+                .map_err(|e| LcAppError::CassetteError(e.to_string()))?;
+            return check_response(status, None, interaction.response);
+        }
+
+        let endpoint = client.host().url(&path);
         let request = if self.is_post() {
-            get_client()
-                .post(self.get_endpoint())
-                .json(&self.get_body())
+            client.http().post(endpoint).json(&body)
         } else {
-            get_client().get(self.get_endpoint())
+            client.http().get(endpoint)
         };
         let response = request
             .header("Content-Type", "application/json")
             .send()
             .await?;
 
-        if response.status().as_u16() == 403 {
-            return Err(LcAppError::CookiesExpiredError);
-        } else if response.status().as_u16() != 200 {
-            return Err(LcAppError::StatusCodeError {
-                code: response.status().to_string(),
-                contents: response.text().await?,
-            });
+        let status = response.status();
+        let retry_after = parse_retry_after(response.headers().get("Retry-After"));
+        let contents = response.text().await?;
+        if let Some(cassette) = cassette {
+            cassette.record(Interaction {
+                method: method.into(),
+                path,
+                body,
+                status: status.as_u16(),
+                response: contents.clone(),
+            })?;
         }
-        let result = response.text().await?;
+        check_response(status, retry_after, contents)
+    }
+}
 
-        if self.use_cache() {
-            let mut c = get_cache().write().unwrap();
-            c.put(self.get_query_hash(), result.clone());
-        }
-        match serde_json::from_str(result.as_str()) {
-            Ok(parsed_message) => Ok(parsed_message),
-            Err(e) => {
-                log::debug!("{}\n{}", &e, result.as_str());
-                Err(LcAppError::DeserializeError(e))
-            }
-        }
+fn check_response(
+    status: StatusCode,
+    retry_after: Option<Duration>,
+    contents: String,
+) -> AppResult<String> {
+    if status.as_u16() == 403 {
+        return Err(LcAppError::CookiesExpiredError);
+    } else if status.as_u16() == 429 {
+        return Err(LcAppError::RateLimitError { retry_after });
+    } else if status.is_server_error() {
+        return Err(LcAppError::ServerError {
+            code: status.to_string(),
+            contents,
+        });
+    } else if status.as_u16() != 200 {
+        return Err(LcAppError::StatusCodeError {
+            code: status.to_string(),
+            contents,
+        });
+    }
+    check_errors(contents.as_str())?;
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_is_stable() {
+        // reference values of 64 bit FNV-1a
+        assert_eq!(hash_string(""), 0xcbf29ce484222325);
+        assert_eq!(hash_string("a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(hash_string("foobar"), 0x85944171f73967e8);
     }
 }
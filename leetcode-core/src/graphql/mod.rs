@@ -1,3 +1,7 @@
+pub mod cache;
+pub mod cassette;
 pub mod client;
 pub mod query;
+mod response;
+pub mod retry;
 use client::GQLLeetcodeRequest;
@@ -1,5 +1,6 @@
 use super::GQLLeetcodeRequest;
 use serde::Serialize;
+use std::time::Duration;
 
 const QUERY: &str = r#"
 query consolePanelConfig($titleSlug: String!) {
@@ -7,7 +8,7 @@ query consolePanelConfig($titleSlug: String!) {
     questionFrontendId
     questionTitle
     exampleTestcaseList
-    # metaData
+    metaData
   }
 }
 "#;
@@ -40,4 +41,8 @@ impl GQLLeetcodeRequest for Query {
     fn use_cache(&self) -> bool {
         true
     }
+
+    fn cache_ttl(&self) -> Option<Duration> {
+        Some(Duration::from_secs(7 * 24 * 60 * 60))
+    }
 }
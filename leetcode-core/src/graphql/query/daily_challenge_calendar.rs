@@ -0,0 +1,72 @@
+use super::GQLLeetcodeRequest;
+use crate::site::Site;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+const QUERY: &str = r#"
+query dailyCodingQuestionRecords($year: Int!, $month: Int!) {
+  dailyCodingChallengeV2(year: $year, month: $month) {
+    challenges {
+      date
+      userStatus
+      link
+      question {
+        questionFrontendId
+        title
+        titleSlug
+      }
+    }
+  }
+}
+"#;
+
+/// leetcode.cn lists the days directly under `dailyQuestionRecords`.
+const QUERY_CN: &str = r#"
+query dailyQuestionRecords($year: Int!, $month: Int!) {
+  dailyQuestionRecords(year: $year, month: $month) {
+    date
+    userStatus
+    question {
+      questionFrontendId
+      title
+      titleSlug
+    }
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    year: i32,
+    month: u32,
+}
+
+/// Daily challenges of a month along with whether the user solved them.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    variables: Variables,
+}
+
+impl Query {
+    /// `month` starts at 1
+    pub fn new(year: i32, month: u32) -> Self {
+        Self {
+            variables: Variables { year, month },
+        }
+    }
+}
+
+// not cached, the completion changes as soon as the question is solved
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::daily_challenge_calendar::Data;
+
+    fn get_body(&self, site: Site) -> Value {
+        let query = match site {
+            Site::Com => QUERY,
+            Site::Cn => QUERY_CN,
+        };
+        json!({ "query": query, "variables": self.variables })
+    }
+}
@@ -1,7 +1,9 @@
 use crate::types::daily_coding_challenge::IDailyCodingChallenge;
 
 use super::GQLLeetcodeRequest;
+use crate::site::Site;
 use serde::Serialize;
+use serde_json::{json, Value};
 
 const QUERY: &str = r#"
 query questionOfToday {
@@ -30,29 +32,52 @@ query questionOfToday {
   }
 }"#;
 
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Query {
-    query: &'static str,
-}
+/// leetcode.cn serves the daily question as a single element `todayRecord` list.
+const QUERY_CN: &str = r#"
+query questionOfToday {
+  todayRecord {
+    date
+    userStatus
+    question {
+      acRate
+      difficulty
+      freqBar
+      frontendQuestionId: questionFrontendId
+      isFavor
+      paidOnly: isPaidOnly
+      status
+      title
+      translatedTitle
+      titleSlug
+      topicTags {
+        name
+        id
+        slug
+      }
+    }
+  }
+}"#;
+
+#[derive(Serialize, Default)]
+pub struct Query {}
 
 impl Query {
     pub fn new() -> Self {
-        Self {
-            ..Default::default()
-        }
-    }
-}
-
-impl Default for Query {
-    fn default() -> Self {
-        Self { query: QUERY }
+        Self::default()
     }
 }
 
 impl GQLLeetcodeRequest for Query {
     type T = IDailyCodingChallenge;
 
+    fn get_body(&self, site: Site) -> Value {
+        let query = match site {
+            Site::Com => QUERY,
+            Site::Cn => QUERY_CN,
+        };
+        json!({ "query": query })
+    }
+
     fn use_cache(&self) -> bool {
         true
     }
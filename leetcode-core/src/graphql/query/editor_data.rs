@@ -1,5 +1,6 @@
 use super::GQLLeetcodeRequest;
 use serde::Serialize;
+use std::time::Duration;
 
 const QUERY: &str = r#"
 query questionEditorData($titleSlug: String!) {
@@ -8,6 +9,7 @@ query questionEditorData($titleSlug: String!) {
     titleSlug
     questionFrontendId
     content
+    translatedContent
     codeSnippets {
       lang
       langSlug
@@ -47,4 +49,8 @@ impl GQLLeetcodeRequest for Query {
     fn use_cache(&self) -> bool {
         true
     }
+
+    fn cache_ttl(&self) -> Option<Duration> {
+        Some(Duration::from_secs(7 * 24 * 60 * 60))
+    }
 }
@@ -0,0 +1,177 @@
+use super::GQLLeetcodeRequest;
+use crate::errors::{AppResult, LcAppError};
+use crate::types::favorite::{Data, FavoriteList, MutationData};
+use crate::{default_client, LeetcodeClient};
+use serde::Serialize;
+
+const QUERY: &str = r#"
+query favoritesList {
+  favoritesLists {
+    allFavorites {
+      idHash
+      name
+    }
+  }
+}
+"#;
+
+const ADD_MUTATION: &str = r#"
+mutation addQuestionToFavorite($favoriteIdHash: String!, $questionId: String!) {
+  favorite: addQuestionToFavorite(favoriteIdHash: $favoriteIdHash, questionId: $questionId) {
+    ok
+    error
+  }
+}
+"#;
+
+const REMOVE_MUTATION: &str = r#"
+mutation removeQuestionFromFavorite($favoriteIdHash: String!, $questionId: String!) {
+  favorite: removeQuestionFromFavorite(favoriteIdHash: $favoriteIdHash, questionId: $questionId) {
+    ok
+    error
+  }
+}
+"#;
+
+/// Name of the list every account starts with, the one `isFavor` refers to.
+const DEFAULT_LIST_NAME: &str = "Favorite";
+
+/// Favorite lists of the signed in user.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self { query: QUERY }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = Data;
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    favorite_id_hash: String,
+    question_id: String,
+}
+
+/// Adds a question to or removes it from a favorite list.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Mutation {
+    query: &'static str,
+    variables: Variables,
+}
+
+impl Mutation {
+    /// `question_id` is the internal id of the question, not the frontend one.
+    pub fn new(favorite_id_hash: String, question_id: String, favorite: bool) -> Self {
+        Self {
+            query: if favorite {
+                ADD_MUTATION
+            } else {
+                REMOVE_MUTATION
+            },
+            variables: Variables {
+                favorite_id_hash,
+                question_id,
+            },
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Mutation {
+    type T = MutationData;
+}
+
+/// The list `isFavor` refers to. Any other list would be edited behind the
+/// user's back, hence a missing one is an error.
+fn default_list(lists: &[FavoriteList]) -> AppResult<&FavoriteList> {
+    lists
+        .iter()
+        .find(|l| l.name == DEFAULT_LIST_NAME)
+        .ok_or_else(|| LcAppError::FavoriteListNotFoundError(DEFAULT_LIST_NAME.into()))
+}
+
+/// Adds the question to or removes it from the default favorite list.
+pub async fn set_favorite(question_id: String, favorite: bool) -> AppResult<()> {
+    set_favorite_with(&default_client(), question_id, favorite).await
+}
+
+pub async fn set_favorite_with(
+    client: &LeetcodeClient,
+    question_id: String,
+    favorite: bool,
+) -> AppResult<()> {
+    let lists = Query::new()
+        .send_with(client)
+        .await?
+        .data
+        .favorites_lists
+        .all_favorites;
+    let list = default_list(&lists)?;
+    let response = Mutation::new(list.id_hash.clone(), question_id, favorite)
+        .send_with(client)
+        .await?
+        .data
+        .favorite;
+    if response.ok {
+        Ok(())
+    } else {
+        Err(LcAppError::GraphQLError(
+            response
+                .error
+                .unwrap_or_else(|| "could not update favorites".into()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::Site;
+
+    #[test]
+    fn test_mutation_is_picked_by_the_flag() {
+        let body = Mutation::new("hash".into(), "42".into(), true).get_body(Site::Com);
+        assert!(body["query"]
+            .as_str()
+            .unwrap()
+            .contains("addQuestionToFavorite("));
+        assert_eq!(body["variables"]["favoriteIdHash"], "hash");
+        assert_eq!(body["variables"]["questionId"], "42");
+
+        let body = Mutation::new("hash".into(), "42".into(), false).get_body(Site::Com);
+        assert!(body["query"]
+            .as_str()
+            .unwrap()
+            .contains("removeQuestionFromFavorite("));
+    }
+
+    #[test]
+    fn test_only_the_default_list_is_edited() {
+        let list = |name: &str| FavoriteList {
+            id_hash: format!("{name}-hash"),
+            name: name.into(),
+        };
+        let lists = [list("Graphs"), list("Favorite")];
+        assert_eq!(default_list(&lists).unwrap().id_hash, "Favorite-hash");
+        assert!(matches!(
+            default_list(&lists[..1]),
+            Err(LcAppError::FavoriteListNotFoundError(_))
+        ));
+    }
+}
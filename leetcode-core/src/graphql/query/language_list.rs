@@ -0,0 +1,117 @@
+use super::GQLLeetcodeRequest;
+use crate::errors::{AppResult, LcAppError};
+use crate::types::language::{get_registry, LanguageInfo, Languages};
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+
+const QUERY: &str = r#"
+query languageList {
+  languageList {
+    id
+    name
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self { query: QUERY }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = Languages;
+
+    fn use_cache(&self) -> bool {
+        true
+    }
+
+    // a new language shows up once in a while
+    fn cache_ttl(&self) -> Option<Duration> {
+        Some(Duration::from_secs(7 * 24 * 60 * 60))
+    }
+}
+
+/// Adds the languages leetcode knows about to the registry and saves them to
+/// `path`, so that the next start knows them before any request is made.
+pub async fn update_registry(path: &Path) -> AppResult<()> {
+    let languages = Query::new().send().await?;
+    let language_list = languages.data.language_list;
+    get_registry()
+        .write()
+        .unwrap()
+        .merge_language_list(&language_list);
+    save_language_list(path, &language_list)
+}
+
+/// Adds the languages saved by the last [`update_registry`], nothing is
+/// added when they were never fetched.
+pub fn load_registry(path: &Path) -> AppResult<()> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => {
+            return Err(LcAppError::LanguageListFileError(format!(
+                "{}: {e}",
+                path.display()
+            )))
+        }
+    };
+    let language_list: Vec<LanguageInfo> = serde_json::from_str(&contents)?;
+    get_registry()
+        .write()
+        .unwrap()
+        .merge_language_list(&language_list);
+    Ok(())
+}
+
+fn save_language_list(path: &Path, language_list: &[LanguageInfo]) -> AppResult<()> {
+    let contents = serde_json::to_string(language_list)?;
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, contents)
+        .and_then(|_| std::fs::rename(&tmp_path, path))
+        .map_err(|e| LcAppError::LanguageListFileError(format!("{}: {e}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::language::Language;
+
+    #[test]
+    fn test_saved_language_list_is_loaded() {
+        let path = std::env::temp_dir().join(format!(
+            "leetcode-core-languages-{}.json",
+            std::process::id()
+        ));
+        load_registry(&path).unwrap();
+        assert_eq!(Language::from_id(1027).slug(), "1027");
+
+        save_language_list(
+            &path,
+            &[LanguageInfo {
+                id: 1027,
+                name: "cangjie".into(),
+            }],
+        )
+        .unwrap();
+        load_registry(&path).unwrap();
+        assert_eq!(Language::from_id(1027).slug(), "cangjie");
+        std::fs::remove_file(path).ok();
+    }
+}
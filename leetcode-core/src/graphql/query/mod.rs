@@ -1,15 +1,49 @@
 pub mod console_panel_config;
+pub mod daily_challenge_calendar;
 pub mod daily_coding_challenge;
 pub mod editor_data;
+pub mod favorite;
+pub mod language_list;
 pub mod problemset_question_list;
 pub mod question_content;
+pub mod question_detail;
+pub mod question_votes;
 pub mod run_code;
+pub mod study_plan;
+pub mod submission_details;
+pub mod submission_list;
 pub mod submit_code;
+pub mod user_progress;
+pub mod user_status;
 use super::GQLLeetcodeRequest;
-use crate::errors::AppResult;
-use crate::types::run_submit_response::{ParsedResponse, RunSubmitResult};
+use crate::errors::{AppResult, LcAppError};
+use crate::types::run_submit_response::{ParsedResponse, RunSubmitResult, State};
+use crate::{default_client, LeetcodeClient};
 use async_trait::async_trait;
 pub use editor_data::Query as EditorDataRequest;
+use std::time::{Duration, Instant};
+pub use tokio_util::sync::CancellationToken;
+
+/// Controls how the judge result is waited upon.
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    /// Delay between two consecutive checks
+    pub interval: Duration,
+    /// Gives up with [`LcAppError::PollTimeoutError`] once exceeded
+    pub timeout: Duration,
+    /// Stops polling with [`LcAppError::PollCancelledError`] when cancelled
+    pub cancel: CancellationToken,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(60),
+            cancel: CancellationToken::new(),
+        }
+    }
+}
 
 #[async_trait]
 pub trait RunOrSubmitCodeCheckResult<IntermediateResponse>:
@@ -17,14 +51,52 @@ pub trait RunOrSubmitCodeCheckResult<IntermediateResponse>:
 where
     IntermediateResponse: GQLLeetcodeRequest<T = RunSubmitResult> + Send,
 {
-    async fn poll_check_response(&self) -> AppResult<ParsedResponse> {
-        let run_response = self.send().await?;
+    /// Sends the request with the default client, see [`crate::init`].
+    async fn poll_check_response(
+        &self,
+        options: PollOptions,
+        on_state: &(dyn for<'s> Fn(&'s State) + Send + Sync),
+    ) -> AppResult<ParsedResponse> {
+        self.poll_check_response_with(&default_client(), options, on_state)
+            .await
+    }
+
+    /// Sends the request and checks the judge result with `client`. `on_state`
+    /// is called every time the judge moves the submission to a new state.
+    async fn poll_check_response_with(
+        &self,
+        client: &LeetcodeClient,
+        options: PollOptions,
+        on_state: &(dyn for<'s> Fn(&'s State) + Send + Sync),
+    ) -> AppResult<ParsedResponse> {
+        let cancel = options.cancel;
+        let run_response = tokio::select! {
+            _ = cancel.cancelled() => return Err(LcAppError::PollCancelledError),
+            response = self.send_with(client) => response?,
+        };
+        let started_at = Instant::now();
+        let mut last_state = None;
         loop {
-            let status_check = run_response.send().await?;
-            let parsed_response = status_check.to_parsed_response()?;
-            match parsed_response {
-                ParsedResponse::Pending => continue,
-                _ => return Ok(parsed_response),
+            let status_check = tokio::select! {
+                _ = cancel.cancelled() => return Err(LcAppError::PollCancelledError),
+                check = run_response.send_with(client) => check?,
+            };
+            match status_check.get_state()? {
+                Some(state) => {
+                    if last_state.as_ref() != Some(&state) {
+                        on_state(&state);
+                        last_state = Some(state);
+                    }
+                }
+                None => return status_check.to_parsed_response(),
+            }
+
+            if started_at.elapsed() + options.interval > options.timeout {
+                return Err(LcAppError::PollTimeoutError(options.timeout));
+            }
+            tokio::select! {
+                _ = cancel.cancelled() => return Err(LcAppError::PollCancelledError),
+                _ = tokio::time::sleep(options.interval) => (),
             }
         }
     }
@@ -2,6 +2,7 @@ use serde::Serialize;
 use serde_json::{json, Value};
 
 use super::GQLLeetcodeRequest;
+use crate::site::Site;
 
 const QUERY: &str = r#"
 query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $filters: QuestionListFilterInput) {
@@ -33,14 +34,127 @@ query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $fi
     }
 }"#;
 
-#[derive(Serialize)]
+/// leetcode.cn does not expose `questionList`, the translated title comes in as `titleCn`.
+const QUERY_CN: &str = r#"
+query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $filters: QuestionListFilterInput) {
+    problemsetQuestionList(
+        categorySlug: $categorySlug
+        limit: $limit
+        skip: $skip
+        filters: $filters
+    ) {
+        total
+        questions {
+            acRate
+            difficulty
+            freqBar
+            frontendQuestionId
+            isFavor
+            paidOnly
+            status
+            title
+            translatedTitle: titleCn
+            titleSlug
+            topicTags {
+                name
+                id
+                slug
+            }
+        }
+    }
+}"#;
+
+fn query_for(site: Site) -> &'static str {
+    match site {
+        Site::Com => QUERY,
+        Site::Cn => QUERY_CN,
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    #[serde(rename = "NOT_STARTED")]
+    NotStarted,
+    #[serde(rename = "TRIED")]
+    Attempted,
+    #[serde(rename = "AC")]
+    Solved,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderBy {
+    FrontendId,
+    AcRate,
+    Difficulty,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Sent as the `categorySlug` of the query, `All` leaves the list unrestricted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Category {
+    #[default]
+    All,
+    Algorithms,
+    Database,
+    Shell,
+    Concurrency,
+}
+
+impl Category {
+    pub fn slug(&self) -> &'static str {
+        match self {
+            Category::All => "",
+            Category::Algorithms => "algorithms",
+            Category::Database => "database",
+            Category::Shell => "shell",
+            Category::Concurrency => "concurrency",
+        }
+    }
+}
+
+/// `QuestionListFilterInput` of the query, unset fields are left out.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
-struct Filters(Value);
+pub struct Filters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    /// Topic slugs, e.g. `dynamic-programming`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_keywords: Option<String>,
+    /// Id of a study plan or favorite list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<OrderBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
+}
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct Variables {
-    category_slug: String,
+    category_slug: &'static str,
     limit: i32,
     skip: i32,
     filters: Filters,
@@ -49,10 +163,10 @@ struct Variables {
 impl Default for Variables {
     fn default() -> Self {
         Self {
-            category_slug: "".to_string(),
+            category_slug: Category::All.slug(),
             limit: 1,
             skip: 0,
-            filters: Filters(json!({})),
+            filters: Filters::default(),
         }
     }
 }
@@ -67,10 +181,9 @@ impl Variables {
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Query {
-    query: &'static str,
     variables: Variables,
 }
 
@@ -78,20 +191,107 @@ impl Query {
     pub fn new(limit: i32, skip: i32) -> Self {
         Self {
             variables: Variables::new(limit, skip),
-            ..Default::default()
         }
     }
 }
 
-impl Default for Query {
-    fn default() -> Self {
-        Self {
-            query: QUERY,
-            variables: Variables::default(),
-        }
+/// Server side filtering, e.g.
+/// `QuestionRequest::new(100, 0).difficulty(Difficulty::Hard).tag("graph")`
+impl Query {
+    pub fn category(mut self, category: Category) -> Self {
+        self.variables.category_slug = category.slug();
+        self
+    }
+
+    pub fn difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.variables.filters.difficulty = Some(difficulty);
+        self
+    }
+
+    pub fn status(mut self, status: Status) -> Self {
+        self.variables.filters.status = Some(status);
+        self
+    }
+
+    pub fn tag(mut self, slug: impl Into<String>) -> Self {
+        self.variables.filters.tags.push(slug.into());
+        self
+    }
+
+    pub fn search(mut self, keywords: impl Into<String>) -> Self {
+        self.variables.filters.search_keywords = Some(keywords.into());
+        self
+    }
+
+    pub fn list_id(mut self, list_id: impl Into<String>) -> Self {
+        self.variables.filters.list_id = Some(list_id.into());
+        self
+    }
+
+    pub fn premium_only(mut self, premium_only: bool) -> Self {
+        self.variables.filters.premium_only = Some(premium_only);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: OrderBy, sort_order: SortOrder) -> Self {
+        self.variables.filters.order_by = Some(order_by);
+        self.variables.filters.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Replaces every filter set so far.
+    pub fn filters(mut self, filters: Filters) -> Self {
+        self.variables.filters = filters;
+        self
     }
 }
 
 impl GQLLeetcodeRequest for Query {
     type T = crate::types::problemset_question_list::Root;
+
+    fn get_body(&self, site: Site) -> Value {
+        json!({ "query": query_for(site), "variables": self.variables })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unfiltered_query_sends_empty_filters() {
+        let body = Query::new(10, 20).get_body(Site::Com);
+        assert_eq!(body["variables"]["categorySlug"], "");
+        assert_eq!(body["variables"]["filters"], json!({}));
+    }
+
+    #[test]
+    fn test_filters_are_serialized() {
+        let body = Query::new(50, 0)
+            .category(Category::Algorithms)
+            .difficulty(Difficulty::Medium)
+            .status(Status::Solved)
+            .tag("array")
+            .tag("hash-table")
+            .search("two sum")
+            .list_id("wpwgkgt")
+            .premium_only(false)
+            .order_by(OrderBy::FrontendId, SortOrder::Descending)
+            .get_body(Site::Com);
+        assert_eq!(body["variables"]["categorySlug"], "algorithms");
+        assert_eq!(
+            body["variables"]["filters"],
+            json!({
+                "difficulty": "MEDIUM",
+                "status": "AC",
+                "tags": ["array", "hash-table"],
+                "searchKeywords": "two sum",
+                "listId": "wpwgkgt",
+                "premiumOnly": false,
+                "orderBy": "FRONTEND_ID",
+                "sortOrder": "DESCENDING"
+            })
+        );
+    }
 }
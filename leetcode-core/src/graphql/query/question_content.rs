@@ -1,10 +1,12 @@
 use super::GQLLeetcodeRequest;
 use serde::Serialize;
+use std::time::Duration;
 
 const QUERY: &str = r#"
 query questionContent($titleSlug: String!) {
   question(titleSlug: $titleSlug) {
     content
+    translatedContent
     titleSlug
   }
 }
@@ -38,4 +40,8 @@ impl GQLLeetcodeRequest for Query {
     fn use_cache(&self) -> bool {
         true
     }
+
+    fn cache_ttl(&self) -> Option<Duration> {
+        Some(Duration::from_secs(7 * 24 * 60 * 60))
+    }
 }
@@ -0,0 +1,65 @@
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+use std::time::Duration;
+
+const QUERY: &str = r#"
+query questionDetail($titleSlug: String!) {
+  question(titleSlug: $titleSlug) {
+    questionId
+    questionFrontendId
+    title
+    titleSlug
+    content
+    translatedContent
+    difficulty
+    isPaidOnly
+    likes
+    dislikes
+    hints
+    similarQuestions
+    stats
+    sampleTestCase
+    metaData
+    companyTagStats
+    topicTags {
+      name
+      slug
+    }
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    title_slug: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+    variables: Variables,
+}
+
+impl Query {
+    pub fn new(title_slug: String) -> Self {
+        Self {
+            query: QUERY,
+            variables: Variables { title_slug },
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::question_detail::Data;
+
+    fn use_cache(&self) -> bool {
+        true
+    }
+
+    // likes and acceptance move slowly, a day old copy is good enough
+    fn cache_ttl(&self) -> Option<Duration> {
+        Some(Duration::from_secs(24 * 60 * 60))
+    }
+}
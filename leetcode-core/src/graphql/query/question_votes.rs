@@ -0,0 +1,57 @@
+//! Votes of several questions in one request. The problemset list does not
+//! carry them, so each question is queried under an alias of its own.
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Questions asked for in a single request.
+pub const BATCH_SIZE: usize = 50;
+
+const FIELDS: &str = "questionFrontendId likes dislikes stats";
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: String,
+    variables: BTreeMap<String, String>,
+}
+
+impl Query {
+    pub fn new(title_slugs: &[String]) -> Self {
+        let params = (0..title_slugs.len())
+            .map(|i| format!("$s{i}: String!"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let selections = (0..title_slugs.len())
+            .map(|i| format!("  q{i}: question(titleSlug: $s{i}) {{ {FIELDS} }}\n"))
+            .collect::<String>();
+        Self {
+            query: format!("query questionVotes({params}) {{\n{selections}}}"),
+            variables: title_slugs
+                .iter()
+                .enumerate()
+                .map(|(i, slug)| (format!("s{i}"), slug.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::question_votes::Data;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::Site;
+
+    #[test]
+    fn test_every_slug_gets_an_alias() {
+        let body = Query::new(&["two-sum".into(), "3sum".into()]).get_body(Site::Com);
+        let query = body["query"].as_str().unwrap();
+        assert!(query.starts_with("query questionVotes($s0: String!, $s1: String!) {"));
+        assert!(query.contains("q1: question(titleSlug: $s1) { questionFrontendId"));
+        assert_eq!(body["variables"]["s0"], "two-sum");
+        assert_eq!(body["variables"]["s1"], "3sum");
+    }
+}
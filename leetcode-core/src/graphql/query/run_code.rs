@@ -1,16 +1,25 @@
 use super::{GQLLeetcodeRequest, RunOrSubmitCodeCheckResult};
 pub use crate::types::{
-    run::{RunCodeIntermediateResponse, RunCodeRequest},
+    run::{RunCodeIntermediateResponse, RunCodeRequest, TestCase},
     run_submit_response::RunSubmitResult,
 };
-use crate::{errors::LcAppError, graphql::query::console_panel_config};
+use crate::{
+    default_client,
+    errors::{AppResult, LcAppError},
+    graphql::query::console_panel_config,
+    LeetcodeClient,
+};
 
 impl GQLLeetcodeRequest for RunCodeRequest {
     type T = RunCodeIntermediateResponse;
 
-    fn get_endpoint(&self) -> String {
+    fn get_path(&self) -> String {
         let slug = self.slug.as_str();
-        format!("https://leetcode.com/problems/{slug}/interpret_solution/")
+        format!("/problems/{slug}/interpret_solution/")
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
     }
 }
 
@@ -20,26 +29,47 @@ impl GQLLeetcodeRequest for RunCodeIntermediateResponse {
         false
     }
 
-    fn get_endpoint(&self) -> String {
+    fn get_path(&self) -> String {
         let interpret_id = self.interpret_id.as_str();
-        format!("https://leetcode.com/submissions/detail/{interpret_id}/check/")
+        format!("/submissions/detail/{interpret_id}/check/")
     }
 }
 
 impl RunCodeRequest {
     pub async fn set_sample_test_cases_if_none(&mut self) -> Result<(), LcAppError> {
+        self.set_sample_test_cases_if_none_with(&default_client())
+            .await
+    }
+
+    pub async fn set_sample_test_cases_if_none_with(
+        &mut self,
+        client: &LeetcodeClient,
+    ) -> Result<(), LcAppError> {
         if self.test_cases_stdin.is_none() {
-            let fetched_test_cases = console_panel_config::Query::new(self.slug.clone())
-                .send()
-                .await?
-                .data
-                .question
-                .example_testcase_list
-                .join("\n");
-            self.test_cases_stdin = Some(fetched_test_cases);
+            let samples = self.get_sample_test_cases_with(client).await?;
+            self.set_test_cases(&samples);
         }
         Ok(())
     }
+
+    pub async fn get_sample_test_cases(&self) -> AppResult<Vec<TestCase>> {
+        self.get_sample_test_cases_with(&default_client()).await
+    }
+
+    pub async fn get_sample_test_cases_with(
+        &self,
+        client: &LeetcodeClient,
+    ) -> AppResult<Vec<TestCase>> {
+        Ok(console_panel_config::Query::new(self.slug.clone())
+            .send_with(client)
+            .await?
+            .data
+            .question
+            .example_testcase_list
+            .into_iter()
+            .map(TestCase::sample)
+            .collect())
+    }
 }
 
 impl RunOrSubmitCodeCheckResult<RunCodeIntermediateResponse> for RunCodeRequest {}
@@ -53,7 +83,7 @@ mod tests {
     #[test]
     fn test() {
         let s = RunCodeRequest {
-            lang: crate::types::language::Language::Python3,
+            lang: crate::types::language::Language::new("python3"),
             question_id: "1".to_string(),
             typed_code: "class Solution:\n    def twoSum(self, nums: List[int], target: int) -> List[int]:    return [4]".to_string(),
             test_cases_stdin: Some("[2,7,11,15]\n9\n[3,2,4]\n6\n[3,3]\n6".to_string()),
@@ -0,0 +1,57 @@
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+use std::time::Duration;
+
+const QUERY: &str = r#"
+query studyPlanDetail($slug: String!) {
+  studyPlanV2Detail(planSlug: $slug) {
+    slug
+    name
+    planSubGroups {
+      slug
+      name
+      questions {
+        questionFrontendId
+        title
+        titleSlug
+      }
+    }
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    slug: String,
+}
+
+/// Questions of a study plan, e.g. `leetcode-75` or `top-interview-150`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+    variables: Variables,
+}
+
+impl Query {
+    pub fn new(slug: impl Into<String>) -> Self {
+        Self {
+            query: QUERY,
+            variables: Variables { slug: slug.into() },
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::study_plan::Data;
+
+    fn use_cache(&self) -> bool {
+        true
+    }
+
+    // plans are curated, they hardly ever change
+    fn cache_ttl(&self) -> Option<Duration> {
+        Some(Duration::from_secs(24 * 60 * 60))
+    }
+}
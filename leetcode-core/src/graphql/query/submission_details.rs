@@ -0,0 +1,64 @@
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+use std::time::Duration;
+
+const QUERY: &str = r#"
+query submissionDetails($submissionId: Int!) {
+  submissionDetails(submissionId: $submissionId) {
+    runtime
+    runtimeDisplay
+    runtimePercentile
+    runtimeDistribution
+    memory
+    memoryDisplay
+    memoryPercentile
+    memoryDistribution
+    code
+    timestamp
+    statusCode
+    lang {
+      name
+      verboseName
+    }
+    question {
+      questionId
+      titleSlug
+    }
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    submission_id: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+    variables: Variables,
+}
+
+impl Query {
+    pub fn new(submission_id: u64) -> Self {
+        Self {
+            query: QUERY,
+            variables: Variables { submission_id },
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::submission_details::Root;
+
+    // A submission never changes once judged
+    fn use_cache(&self) -> bool {
+        true
+    }
+
+    fn cache_ttl(&self) -> Option<Duration> {
+        Some(Duration::from_secs(365 * 24 * 60 * 60))
+    }
+}
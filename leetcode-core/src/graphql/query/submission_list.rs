@@ -0,0 +1,131 @@
+use super::GQLLeetcodeRequest;
+use crate::errors::AppResult;
+use crate::site::Site;
+use crate::types::submission_list::Submission;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+const QUERY: &str = r#"
+query submissionList($offset: Int!, $limit: Int!, $lastKey: String, $questionSlug: String!) {
+  questionSubmissionList(
+    offset: $offset
+    limit: $limit
+    lastKey: $lastKey
+    questionSlug: $questionSlug
+  ) {
+    lastKey
+    hasNext
+    submissions {
+      id
+      statusDisplay
+      lang
+      runtime
+      memory
+      timestamp
+    }
+  }
+}
+"#;
+
+const PAGE_SIZE: i32 = 20;
+
+const QUERY_CN: &str = r#"
+query submissionList($offset: Int!, $limit: Int!, $lastKey: String, $questionSlug: String!) {
+  questionSubmissionList: submissionList(
+    offset: $offset
+    limit: $limit
+    lastKey: $lastKey
+    questionSlug: $questionSlug
+  ) {
+    lastKey
+    hasNext
+    submissions {
+      id
+      statusDisplay
+      lang
+      runtime
+      memory
+      timestamp
+    }
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    offset: i32,
+    limit: i32,
+    last_key: Option<String>,
+    question_slug: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    variables: Variables,
+}
+
+impl Query {
+    /// The most recent page only, see [`fetch_all`] for the whole history.
+    pub fn new(question_slug: String) -> Self {
+        Self::with_page(question_slug, 0, PAGE_SIZE, None)
+    }
+
+    /// `last_key` is the cursor returned by the previous page.
+    pub fn with_page(
+        question_slug: String,
+        offset: i32,
+        limit: i32,
+        last_key: Option<String>,
+    ) -> Self {
+        Self {
+            variables: Variables {
+                offset,
+                limit,
+                last_key,
+                question_slug,
+            },
+        }
+    }
+}
+
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::submission_list::Root;
+
+    fn get_body(&self, site: Site) -> Value {
+        let query = match site {
+            Site::Com => QUERY,
+            Site::Cn => QUERY_CN,
+        };
+        json!({ "query": query, "variables": self.variables })
+    }
+}
+
+/// Every submission of the question, newest first. Follows `lastKey` until
+/// leetcode reports no further page.
+pub async fn fetch_all(question_slug: &str) -> AppResult<Vec<Submission>> {
+    let mut submissions = vec![];
+    let mut last_key = None;
+    loop {
+        let page = Query::with_page(
+            question_slug.to_string(),
+            submissions.len() as i32,
+            PAGE_SIZE,
+            last_key,
+        )
+        .send()
+        .await?;
+        let has_next = page.has_next();
+        last_key = page.get_last_key().map(String::from);
+        let fetched = page.get_submissions();
+        if fetched.is_empty() {
+            break;
+        }
+        submissions.extend(fetched);
+        if !has_next {
+            break;
+        }
+    }
+    Ok(submissions)
+}
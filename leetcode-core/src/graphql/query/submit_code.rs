@@ -5,9 +5,13 @@ pub use crate::types::submit::{SubmitCodeIntermediateResponse, SubmitCodeRequest
 impl GQLLeetcodeRequest for SubmitCodeRequest {
     type T = SubmitCodeIntermediateResponse;
 
-    fn get_endpoint(&self) -> String {
+    fn get_path(&self) -> String {
         let slug = self.slug.as_str();
-        format!("https://leetcode.com/problems/{slug}/submit/")
+        format!("/problems/{slug}/submit/")
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
     }
 }
 
@@ -18,9 +22,9 @@ impl GQLLeetcodeRequest for SubmitCodeIntermediateResponse {
         false
     }
 
-    fn get_endpoint(&self) -> String {
+    fn get_path(&self) -> String {
         let submission_id = self.submission_id;
-        format!("https://leetcode.com/submissions/detail/{submission_id}/check/")
+        format!("/submissions/detail/{submission_id}/check/")
     }
 }
 
@@ -47,7 +51,7 @@ mod tests {
 
         // Define expected request body
         let expected_request_body = SubmitCodeRequest {
-            lang: crate::types::language::Language::Python3,
+            lang: crate::types::language::Language::new("python3"),
             question_id: "1".to_string(),
             typed_code: "class Solution:\n    def twoSum(self, nums: List[int], target: int) -> List[int]:    return [1]".to_string(),
             slug: "".to_string()
@@ -0,0 +1,54 @@
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+
+const QUERY: &str = r#"
+query userProgressQuestionList($filters: UserProgressQuestionListInput) {
+  userProgressQuestionList(filters: $filters) {
+    totalNum
+    questions {
+      frontendId
+      title
+      titleSlug
+      lastSubmittedAt
+      questionStatus
+    }
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Filters {
+    skip: i32,
+    limit: i32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Variables {
+    filters: Filters,
+}
+
+/// Questions the user has submitted to, the most recently submitted first.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+    variables: Variables,
+}
+
+impl Query {
+    pub fn new(limit: i32, skip: i32) -> Self {
+        Self {
+            query: QUERY,
+            variables: Variables {
+                filters: Filters { skip, limit },
+            },
+        }
+    }
+}
+
+// never cached, it is how a sync learns about the latest submissions
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::user_progress::Data;
+}
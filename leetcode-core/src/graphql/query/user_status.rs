@@ -0,0 +1,37 @@
+use super::GQLLeetcodeRequest;
+use serde::Serialize;
+
+const QUERY: &str = r#"
+query globalData {
+  userStatus {
+    isSignedIn
+    username
+    isPremium
+  }
+}
+"#;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    query: &'static str,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self { query: QUERY }
+    }
+}
+
+// never cached, it is what tells whether the cookies are still valid
+impl GQLLeetcodeRequest for Query {
+    type T = crate::types::user_status::Data;
+}
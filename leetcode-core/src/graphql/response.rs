@@ -0,0 +1,120 @@
+//! Leetcode answers failed queries with `200 OK` and an `errors` list in the body.
+use crate::errors::{AppResult, LcAppError};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+struct GraphQLError {
+    message: String,
+    #[serde(default)]
+    extensions: Option<Extensions>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Extensions {
+    code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    errors: Vec<GraphQLError>,
+    #[serde(default)]
+    data: Value,
+}
+
+impl ErrorResponse {
+    /// Partial responses still carry the data that could be resolved, only the ones
+    /// without anything usable are treated as failures.
+    fn has_data(&self) -> bool {
+        match &self.data {
+            Value::Null => false,
+            Value::Object(fields) => fields.values().any(|v| !v.is_null()),
+            _ => true,
+        }
+    }
+}
+
+/// Only the errors leetcode reports with a known code or message get a variant
+/// of their own, a message merely looking alike could come from any query.
+fn to_app_error(error: GraphQLError) -> LcAppError {
+    let code = error.extensions.and_then(|e| e.code);
+    let message = error.message;
+    if code.as_deref() == Some("UNAUTHENTICATED") {
+        return LcAppError::Unauthenticated(message);
+    }
+    let normalized = message.trim().trim_end_matches('.').to_lowercase();
+    match normalized.as_str() {
+        "user is not authenticated" | "user is not logged in" => {
+            LcAppError::Unauthenticated(message)
+        }
+        "question matching query does not exist" => LcAppError::QuestionNotFound(message),
+        _ if normalized.contains("premium") => LcAppError::PremiumRequired(message),
+        _ => LcAppError::GraphQLError(message),
+    }
+}
+
+/// Fails with the first reported error when the body has no data to deserialize.
+pub(crate) fn check_errors(body: &str) -> AppResult<()> {
+    let Ok(response) = serde_json::from_str::<ErrorResponse>(body) else {
+        return Ok(());
+    };
+    if response.has_data() {
+        return Ok(());
+    }
+    match response.errors.into_iter().next() {
+        Some(error) => Err(to_app_error(error)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_without_data_are_mapped() {
+        let not_found = r#"{"errors": [{"message": "Question matching query does not exist.", "path": ["question"]}], "data": {"question": null}}"#;
+        assert!(matches!(
+            check_errors(not_found),
+            Err(LcAppError::QuestionNotFound(_))
+        ));
+        let premium = r#"{"errors": [{"message": "This question is only available to premium subscribers."}], "data": null}"#;
+        assert!(matches!(
+            check_errors(premium),
+            Err(LcAppError::PremiumRequired(_))
+        ));
+        let unauthenticated = r#"{"errors": [{"message": "User is not authenticated"}]}"#;
+        assert!(matches!(
+            check_errors(unauthenticated),
+            Err(LcAppError::Unauthenticated(_))
+        ));
+        let by_code = r#"{"errors": [{"message": "Sign in first", "extensions": {"code": "UNAUTHENTICATED"}}]}"#;
+        assert!(matches!(
+            check_errors(by_code),
+            Err(LcAppError::Unauthenticated(_))
+        ));
+        for other in [
+            "Syntax Error",
+            "FavoriteList matching query does not exist.",
+            "Submission not found",
+            "You do not have permission to perform this action",
+        ] {
+            let body = format!(r#"{{"errors": [{{"message": "{other}"}}], "data": null}}"#);
+            assert!(
+                matches!(check_errors(&body), Err(LcAppError::GraphQLError(_))),
+                "{other}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_responses_with_data_pass() {
+        assert!(check_errors(r#"{"data": {"question": {"title": "Two Sum"}}}"#).is_ok());
+        let partial = r#"{"errors": [{"message": "not found"}], "data": {"a": null, "b": 1}}"#;
+        assert!(check_errors(partial).is_ok());
+        // non graphql endpoints, e.g. the judge check
+        assert!(check_errors(r#"{"state": "PENDING"}"#).is_ok());
+        assert!(check_errors("[]").is_ok());
+    }
+}
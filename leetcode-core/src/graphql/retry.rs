@@ -0,0 +1,150 @@
+use crate::errors::LcAppError;
+use rand::Rng;
+use reqwest::header::HeaderValue;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub static RETRY_POLICY: OnceLock<RetryPolicy> = OnceLock::new();
+
+pub fn init(policy: RetryPolicy) {
+    RETRY_POLICY.get_or_init(|| policy);
+}
+
+pub(crate) fn get_retry_policy() -> &'static RetryPolicy {
+    RETRY_POLICY.get_or_init(RetryPolicy::default)
+}
+
+/// Exponential backoff with jitter for the failures that are worth another attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `idempotent` requests are retried on server and connection errors as well,
+    /// others only when leetcode explicitly rejected them for rate limiting.
+    /// A `Retry-After` longer than `max_delay` gives up right away rather than
+    /// parking the request.
+    pub fn should_retry(&self, attempt: u32, error: &LcAppError, idempotent: bool) -> bool {
+        if attempt >= self.max_retries {
+            return false;
+        }
+        match error {
+            LcAppError::RateLimitError { retry_after } => {
+                retry_after.is_none_or(|retry_after| retry_after <= self.max_delay)
+            }
+            LcAppError::ServerError { .. } => idempotent,
+            LcAppError::RequestError(e) => idempotent && (e.is_timeout() || e.is_connect()),
+            _ => false,
+        }
+    }
+
+    /// Delay before the retry number `attempt` (0 based). `Retry-After` sent by the
+    /// server takes precedence over the computed backoff, both are capped by `max_delay`.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let mut rng = rand::thread_rng();
+        if let Some(retry_after) = retry_after {
+            let jitter = self.base_delay.mul_f64(rng.gen_range(0.0..1.0));
+            return (retry_after + jitter).min(self.max_delay);
+        }
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        // equal jitter: half fixed, half random
+        backoff / 2 + (backoff / 2).mul_f64(rng.gen_range(0.0..=1.0))
+    }
+}
+
+/// Only the delay-seconds form is understood, an http-date falls back to the backoff.
+pub(crate) fn parse_retry_after(value: Option<&HeaderValue>) -> Option<Duration> {
+    value?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+        for attempt in 0..6 {
+            let expected = Duration::from_millis(100 * 2u64.pow(attempt)).min(policy.max_delay);
+            let delay = policy.delay(attempt, None);
+            assert!(delay >= expected / 2 && delay <= expected, "{delay:?}");
+        }
+    }
+
+    #[test]
+    fn test_retry_after_takes_precedence() {
+        let policy = RetryPolicy::default();
+        let delay = policy.delay(0, Some(Duration::from_secs(5)));
+        assert!(delay >= Duration::from_secs(5));
+        assert!(delay < Duration::from_secs(5) + policy.base_delay);
+    }
+
+    #[test]
+    fn test_retry_after_is_capped() {
+        let policy = RetryPolicy::default();
+        let delay = policy.delay(0, Some(policy.max_delay));
+        assert_eq!(delay, policy.max_delay);
+
+        let hours = LcAppError::RateLimitError {
+            retry_after: Some(Duration::from_secs(3600)),
+        };
+        assert!(!policy.should_retry(0, &hours, true));
+        let short = LcAppError::RateLimitError {
+            retry_after: Some(Duration::from_secs(1)),
+        };
+        assert!(policy.should_retry(0, &short, true));
+    }
+
+    #[test]
+    fn test_retry_decisions() {
+        let policy = RetryPolicy::default();
+        let rate_limited = LcAppError::RateLimitError { retry_after: None };
+        let server_error = LcAppError::ServerError {
+            code: "502".into(),
+            contents: "".into(),
+        };
+        assert!(policy.should_retry(0, &rate_limited, false));
+        assert!(!policy.should_retry(3, &rate_limited, true));
+        assert!(policy.should_retry(1, &server_error, true));
+        assert!(!policy.should_retry(1, &server_error, false));
+        assert!(!policy.should_retry(0, &LcAppError::CookiesExpiredError, true));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let seconds = HeaderValue::from_static(" 12 ");
+        let date = HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT");
+        assert_eq!(
+            parse_retry_after(Some(&seconds)),
+            Some(Duration::from_secs(12))
+        );
+        assert_eq!(parse_retry_after(Some(&date)), None);
+        assert_eq!(parse_retry_after(None), None);
+    }
+}
@@ -1,32 +1,80 @@
+pub mod client;
 pub mod errors;
 pub mod graphql;
+pub mod network;
+pub mod site;
 pub mod types;
+pub use client::LeetcodeClient;
 use errors::AppResult;
 pub use graphql::client::GQLLeetcodeRequest;
+pub use graphql::query::daily_challenge_calendar::Query as DailyChallengeCalendarRequest;
+pub use graphql::query::favorite::{Mutation as FavoriteMutation, Query as FavoriteListsRequest};
+pub use graphql::query::language_list::Query as LanguageListRequest;
 pub use graphql::query::problemset_question_list::Query as QuestionRequest;
 pub use graphql::query::question_content::Query as QuestionContentRequest;
+pub use graphql::query::question_detail::Query as QuestionDetailRequest;
+pub use graphql::query::question_votes::Query as QuestionVotesRequest;
 pub use graphql::query::run_code::RunCodeRequest;
+pub use graphql::query::study_plan::Query as StudyPlanRequest;
+pub use graphql::query::submission_details::Query as SubmissionDetailsRequest;
+pub use graphql::query::submission_list::Query as SubmissionListRequest;
 pub use graphql::query::submit_code::SubmitCodeRequest;
+pub use graphql::query::user_progress::Query as UserProgressRequest;
+pub use graphql::query::user_status::Query as UserStatusRequest;
 pub use graphql::query::EditorDataRequest;
+pub use network::NetworkOptions;
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::Client;
-use std::sync::OnceLock;
+pub use site::{Host, Site};
+use std::sync::{OnceLock, RwLock};
 pub use types::editor_data::QuestionData as EditorDataResponse;
 pub use types::problemset_question_list::Root as QuestionResponse;
 
-pub static REQ_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+/// Client used by [`GQLLeetcodeRequest::send`], set up by [`init`].
+pub static REQ_CLIENT: OnceLock<RwLock<LeetcodeClient>> = OnceLock::new();
 
-pub async fn init(csrf: &str, sess: &str) -> AppResult<()> {
-    let client = build_reqwest_client(csrf, sess).await?;
-    REQ_CLIENT.get_or_init(|| client);
+pub async fn init(csrf: &str, sess: &str, host: Host, network: NetworkOptions) -> AppResult<()> {
+    let mut client = LeetcodeClient::new(csrf, sess, host, network)
+        .await?
+        .with_memory_cache(graphql::client::get_cache().clone());
+    if let Some(disk_cache) = graphql::cache::DISK_CACHE.get() {
+        client = client.with_disk_cache(disk_cache.clone());
+    }
+    if let Some(cassette) = graphql::cassette::CASSETTE.get() {
+        client = client.with_cassette(cassette.clone());
+    }
+    REQ_CLIENT.get_or_init(|| RwLock::new(client));
+    Ok(())
+}
+
+/// Swaps the cookies of the default client, requests sent afterwards use the new ones.
+pub async fn set_credentials(csrf: &str, sess: &str) -> AppResult<()> {
+    let mut client = default_client();
+    client.set_credentials(csrf, sess).await?;
+    *REQ_CLIENT
+        .get()
+        .expect("Client not initialized")
+        .write()
+        .unwrap() = client;
     Ok(())
 }
 
-pub(crate) fn get_client() -> &'static Client {
-    REQ_CLIENT.get().expect("Client not initialized")
+/// The client set up by [`init`]. Cloning it is cheap.
+pub fn default_client() -> LeetcodeClient {
+    REQ_CLIENT
+        .get()
+        .expect("Client not initialized")
+        .read()
+        .unwrap()
+        .clone()
 }
 
-pub async fn build_reqwest_client(csrf: &str, sess: &str) -> AppResult<Client> {
+pub async fn build_reqwest_client(
+    csrf: &str,
+    sess: &str,
+    host: &Host,
+    network: &NetworkOptions,
+) -> AppResult<Client> {
     let mut headers = HeaderMap::new();
     let header_k_v = [
         (
@@ -35,8 +83,8 @@ pub async fn build_reqwest_client(csrf: &str, sess: &str) -> AppResult<Client> {
         ),
         ("Content-Type", "application/json".to_string()),
         ("x-csrftoken", csrf.to_string()),
-        ("Origin", "https://leetcode.com".to_string()),
-        ("Referer", "https://leetcode.com".to_string()),
+        ("Origin", host.base_url().to_string()),
+        ("Referer", host.base_url().to_string()),
         ("Connection", "keep-alive".to_string()),
     ];
 
@@ -44,7 +92,8 @@ pub async fn build_reqwest_client(csrf: &str, sess: &str) -> AppResult<Client> {
         headers.append(key, HeaderValue::from_str(value.as_str())?);
     }
 
-    let client = reqwest::ClientBuilder::new()
+    let client = network
+        .apply(reqwest::ClientBuilder::new())?
         .default_headers(headers)
         .build()?;
     Ok(client)
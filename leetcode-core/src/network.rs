@@ -0,0 +1,110 @@
+use crate::errors::{AppResult, LcAppError};
+use reqwest::{Certificate, ClientBuilder, Proxy};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Transport settings of the http client, for networks behind a proxy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkOptions {
+    /// Every request goes through it, e.g. `http://proxy.corp:3128`
+    pub proxy: Option<String>,
+    /// Honors `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` when no proxy is set
+    pub use_env_proxy: bool,
+    pub connect_timeout: Option<Duration>,
+    /// Whole request, from connecting until the response is read
+    pub timeout: Option<Duration>,
+    pub user_agent: Option<String>,
+    /// Extra PEM root certificate, e.g. of a proxy inspecting TLS traffic
+    pub ca_certificate: Option<PathBuf>,
+    pub accept_invalid_certs: bool,
+}
+
+impl Default for NetworkOptions {
+    fn default() -> Self {
+        Self {
+            proxy: None,
+            use_env_proxy: true,
+            connect_timeout: None,
+            timeout: None,
+            user_agent: None,
+            ca_certificate: None,
+            accept_invalid_certs: false,
+        }
+    }
+}
+
+impl NetworkOptions {
+    pub(crate) fn apply(&self, mut builder: ClientBuilder) -> AppResult<ClientBuilder> {
+        match &self.proxy {
+            Some(proxy) => {
+                let proxy = Proxy::all(proxy)
+                    .map_err(|e| LcAppError::NetworkConfigError(format!("proxy {proxy:?}: {e}")))?;
+                builder = builder.proxy(proxy);
+            }
+            // reqwest reads the environment unless told otherwise
+            None if !self.use_env_proxy => builder = builder.no_proxy(),
+            None => (),
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(path) = &self.ca_certificate {
+            let certificate = std::fs::read(path)
+                .map_err(|e| e.to_string())
+                .and_then(|pem| Certificate::from_pem(&pem).map_err(|e| e.to_string()))
+                .map_err(|e| {
+                    LcAppError::NetworkConfigError(format!("certificate {}: {e}", path.display()))
+                })?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        Ok(builder.danger_accept_invalid_certs(self.accept_invalid_certs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(options: NetworkOptions) -> AppResult<reqwest::Client> {
+        Ok(options.apply(ClientBuilder::new())?.build()?)
+    }
+
+    #[test]
+    fn test_valid_options_build_a_client() {
+        assert!(build(NetworkOptions::default()).is_ok());
+        assert!(build(NetworkOptions {
+            proxy: Some("http://127.0.0.1:3128".into()),
+            connect_timeout: Some(Duration::from_secs(5)),
+            timeout: Some(Duration::from_secs(30)),
+            user_agent: Some("leetcode-tui".into()),
+            ..Default::default()
+        })
+        .is_ok());
+    }
+
+    #[test]
+    fn test_invalid_options_are_reported() {
+        let missing_certificate = NetworkOptions {
+            ca_certificate: Some("/does/not/exist.pem".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            build(missing_certificate),
+            Err(LcAppError::NetworkConfigError(_))
+        ));
+        let invalid_proxy = NetworkOptions {
+            proxy: Some("not a url".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            build(invalid_proxy),
+            Err(LcAppError::NetworkConfigError(_))
+        ));
+    }
+}
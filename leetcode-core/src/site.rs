@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Site {
+    #[default]
+    Com,
+    Cn,
+}
+
+impl Site {
+    pub fn default_base_url(&self) -> &'static str {
+        match self {
+            Site::Com => "https://leetcode.com",
+            Site::Cn => "https://leetcode.cn",
+        }
+    }
+}
+
+/// Site flavour along with the base url the requests are sent to.
+/// The base url can be overridden to point the client at a mirror or a mock server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Host {
+    site: Site,
+    base_url: String,
+}
+
+impl Default for Host {
+    fn default() -> Self {
+        Self::new(Site::default(), None)
+    }
+}
+
+impl Host {
+    pub fn new(site: Site, base_url: Option<&str>) -> Self {
+        let base_url = base_url
+            .unwrap_or(site.default_base_url())
+            .trim_end_matches('/')
+            .to_string();
+        Self { site, base_url }
+    }
+
+    pub fn site(&self) -> Site {
+        self.site
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_str()
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path.trim_start_matches('/'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_host_points_to_leetcode_com() {
+        let host = Host::default();
+        assert_eq!(host.site(), Site::Com);
+        assert_eq!(host.url("/graphql"), "https://leetcode.com/graphql");
+    }
+
+    #[test]
+    fn test_base_url_override_is_normalized() {
+        let host = Host::new(Site::Cn, Some("http://127.0.0.1:8080/"));
+        assert_eq!(host.site(), Site::Cn);
+        assert_eq!(
+            host.url("problems/two-sum/submit/"),
+            "http://127.0.0.1:8080/problems/two-sum/submit/"
+        );
+    }
+
+    #[test]
+    fn test_site_deserialization() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            site: Site,
+        }
+        let w: Wrapper = serde_json::from_str(r#"{"site": "cn"}"#).unwrap();
+        assert_eq!(w.site, Site::Cn);
+    }
+}
@@ -1,3 +1,4 @@
+use super::meta_data::MetaData;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -6,7 +7,13 @@ pub struct Question {
     pub question_frontend_id: String,
     pub question_title: String,
     pub example_testcase_list: Vec<String>,
-    // pub meta_data: String,
+    pub meta_data: String,
+}
+
+impl Question {
+    pub fn get_meta_data(&self) -> serde_json::Result<MetaData> {
+        self.meta_data.parse()
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -34,7 +41,8 @@ fn test_parse_json() {
                     "[2,7,11,15]\n9",
                     "[3,2,4]\n6",
                     "[3,3]\n6"
-                ]
+                ],
+                "metaData": "{\n  \"name\": \"twoSum\",\n  \"params\": [\n    {\n      \"name\": \"nums\",\n      \"type\": \"integer[]\"\n    },\n    {\n      \"name\": \"target\",\n      \"type\": \"integer\"\n    }\n  ],\n  \"return\": {\n    \"type\": \"integer[]\",\n    \"size\": 2\n  },\n  \"manual\": false\n}"
             }
         }
     }
@@ -48,8 +56,8 @@ fn test_parse_json() {
             "[3,2,4]\n6".to_string(),
             "[3,3]\n6".to_string(),
         ],
-        // meta_data: "{\n  \"name\": \"twoSum\",\n  \"params\": [\n    {\n      \"name\": \"nums\",\n      \"type\": \"integer[]\"\n    },\n    {\n      \"name\": \"target\",\n      \"type\": \"integer\"\n    }\n  ],\n  \"return\": {\n    \"type\": \"integer[]\",\n    \"size\": 2\n  },\n  \"manual\": false\n}"
-        //     .to_string(),
+        meta_data: "{\n  \"name\": \"twoSum\",\n  \"params\": [\n    {\n      \"name\": \"nums\",\n      \"type\": \"integer[]\"\n    },\n    {\n      \"name\": \"target\",\n      \"type\": \"integer\"\n    }\n  ],\n  \"return\": {\n    \"type\": \"integer[]\",\n    \"size\": 2\n  },\n  \"manual\": false\n}"
+            .to_string(),
     };
 
     let expected_data = Data {
@@ -62,4 +70,11 @@ fn test_parse_json() {
 
     let root = parse_json(json_str);
     assert_eq!(root, expected_root);
+
+    let Ok(MetaData::Function(signature)) = root.data.question.get_meta_data() else {
+        panic!("twoSum has a function signature");
+    };
+    assert_eq!(signature.name, "twoSum");
+    assert_eq!(signature.params.len(), 2);
+    assert_eq!(signature.return_type.size, Some(2));
 }
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Deserializer};
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeQuestion {
+    pub question_frontend_id: String,
+    pub title: String,
+    pub title_slug: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Challenge {
+    /// `YYYY-MM-DD`
+    pub date: String,
+    /// `Finish`/`NotStart`, spelled `FINISH`/`NOT_START` by leetcode.cn
+    pub user_status: String,
+    #[serde(default)]
+    pub link: String,
+    pub question: ChallengeQuestion,
+}
+
+impl Challenge {
+    pub fn is_completed(&self) -> bool {
+        self.user_status.eq_ignore_ascii_case("finish")
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChallengesOrRecords {
+    Challenges { challenges: Vec<Challenge> },
+    Records(Vec<Challenge>),
+}
+
+fn challenges_or_records<'de, D>(deserializer: D) -> Result<Vec<Challenge>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match ChallengesOrRecords::deserialize(deserializer)? {
+        ChallengesOrRecords::Challenges { challenges } => challenges,
+        ChallengesOrRecords::Records(records) => records,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Calendar {
+    #[serde(
+        rename = "dailyCodingChallengeV2",
+        alias = "dailyQuestionRecords",
+        deserialize_with = "challenges_or_records"
+    )]
+    pub challenges: Vec<Challenge>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    pub data: Calendar,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUESTION: &str =
+        r#"{"questionFrontendId": "1", "title": "Two Sum", "titleSlug": "two-sum"}"#;
+
+    #[test]
+    fn test_parse_com_calendar() {
+        let json = format!(
+            r#"{{"data": {{"dailyCodingChallengeV2": {{"challenges": [
+                {{"date": "2024-03-01", "userStatus": "Finish", "link": "/problems/two-sum/", "question": {QUESTION}}},
+                {{"date": "2024-03-02", "userStatus": "NotStart", "link": "/problems/two-sum/", "question": {QUESTION}}}
+            ]}}}}}}"#
+        );
+        let calendar = serde_json::from_str::<Data>(&json).unwrap().data;
+        assert_eq!(calendar.challenges.len(), 2);
+        assert!(calendar.challenges[0].is_completed());
+        assert!(!calendar.challenges[1].is_completed());
+    }
+
+    #[test]
+    fn test_parse_cn_calendar() {
+        let json = format!(
+            r#"{{"data": {{"dailyQuestionRecords": [
+                {{"date": "2024-03-01", "userStatus": "FINISH", "question": {QUESTION}}}
+            ]}}}}"#
+        );
+        let calendar = serde_json::from_str::<Data>(&json).unwrap().data;
+        assert!(calendar.challenges[0].is_completed());
+        assert_eq!(calendar.challenges[0].question.title_slug, "two-sum");
+    }
+}
@@ -1,18 +1,41 @@
 use super::problemset_question_list::Question;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Data {
     pub date: String,
     pub user_status: String,
+    /// Not returned by leetcode.cn
+    #[serde(default)]
     pub link: String,
     pub question: Question,
 }
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(Box<Data>),
+    Many(Vec<Data>),
+}
+
+fn one_or_first<'de, D>(deserializer: D) -> Result<Data, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(data) => Ok(*data),
+        OneOrMany::Many(records) => records
+            .into_iter()
+            .next()
+            .ok_or_else(|| serde::de::Error::custom("no daily coding challenge record found")),
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActiveDailyCodingChallengeQuestion {
+    #[serde(alias = "todayRecord", deserialize_with = "one_or_first")]
     pub active_daily_coding_challenge_question: Data,
 }
 
@@ -21,3 +44,47 @@ pub struct ActiveDailyCodingChallengeQuestion {
 pub struct IDailyCodingChallenge {
     pub data: ActiveDailyCodingChallengeQuestion,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUESTION: &str = r#"{
+        "acRate": 53.2,
+        "difficulty": "Easy",
+        "freqBar": null,
+        "frontendQuestionId": "1",
+        "isFavor": false,
+        "paidOnly": false,
+        "status": null,
+        "title": "Two Sum",
+        "titleSlug": "two-sum",
+        "topicTags": []
+    }"#;
+
+    #[test]
+    fn test_parse_com_daily_challenge() {
+        let json = format!(
+            r#"{{"data": {{"activeDailyCodingChallengeQuestion": {{
+                "date": "2023-10-01", "userStatus": "NotStart",
+                "link": "/problems/two-sum/", "question": {QUESTION}}}}}}}"#
+        );
+        let parsed: IDailyCodingChallenge = serde_json::from_str(&json).unwrap();
+        let daily = parsed.data.active_daily_coding_challenge_question;
+        assert_eq!(daily.link, "/problems/two-sum/");
+        assert_eq!(daily.question.title_slug, "two-sum");
+    }
+
+    #[test]
+    fn test_parse_cn_daily_challenge() {
+        let json = format!(
+            r#"{{"data": {{"todayRecord": [{{
+                "date": "2023-10-01", "userStatus": "FINISH", "question": {QUESTION}}}]}}}}"#
+        );
+        let parsed: IDailyCodingChallenge = serde_json::from_str(&json).unwrap();
+        let daily = parsed.data.active_daily_coding_challenge_question;
+        assert_eq!(daily.user_status, "FINISH");
+        assert_eq!(daily.link, "");
+        assert_eq!(daily.question.frontend_question_id, "1");
+    }
+}
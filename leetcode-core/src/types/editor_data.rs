@@ -19,6 +19,15 @@ pub struct Question {
     pub title_slug: String,
     pub enable_run_code: bool,
     pub content: String,
+    /// Only populated by leetcode.cn
+    #[serde(default)]
+    pub translated_content: Option<String>,
+}
+
+impl Question {
+    pub fn get_content(&self) -> &str {
+        super::localized(&self.content, self.translated_content.as_deref())
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -53,10 +62,14 @@ impl QuestionData {
     }
 
     pub fn get_filename(&self, for_lang: &Language) -> AppResult<String> {
-        if self.get_languages().contains(&for_lang) {
+        let lang_id = self
+            .get_languages()
+            .contains(&for_lang)
+            .then(|| for_lang.to_id())
+            .flatten();
+        if let Some(lang_id) = lang_id {
             let frontend_id = &self.data.question.question_frontend_id;
             let slug = &self.data.question.title_slug;
-            let lang_id: u32 = for_lang.clone().into();
             let extension = for_lang.get_extension();
             let encoded = format!("{frontend_id:0>4}_{slug}_{lang_id}.{extension}",);
             Ok(encoded)
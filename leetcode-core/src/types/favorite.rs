@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteList {
+    pub id_hash: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoritesLists {
+    pub all_favorites: Vec<FavoriteList>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoritesListsData {
+    pub favorites_lists: FavoritesLists,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    pub data: FavoritesListsData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MutationResult {
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MutationResultData {
+    pub favorite: MutationResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MutationData {
+    pub data: MutationResultData,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_favorite_lists_and_mutation() {
+        let json = r#"{"data": {"favoritesLists": {"allFavorites": [
+            {"idHash": "abc123", "name": "Favorite"},
+            {"idHash": "def456", "name": "Graphs"}
+        ]}}}"#;
+        let lists = serde_json::from_str::<Data>(json)
+            .unwrap()
+            .data
+            .favorites_lists
+            .all_favorites;
+        assert_eq!(lists[1].id_hash, "def456");
+
+        let json = r#"{"data": {"favorite": {"ok": false, "error": "question already added"}}}"#;
+        let result = serde_json::from_str::<MutationData>(json)
+            .unwrap()
+            .data
+            .favorite;
+        assert!(!result.ok);
+        assert_eq!(result.error.as_deref(), Some("question already added"));
+    }
+}
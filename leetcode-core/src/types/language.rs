@@ -1,320 +1,293 @@
-use std::fmt::Display;
-
+//! Languages are identified by the slug leetcode uses, e.g. `python3`. What the
+//! app needs to know about each of them, the id encoded in the solution file
+//! names, the extension, how to comment and how to format, comes from a
+//! [`LanguageRegistry`]. It starts from a builtin table, learns the languages
+//! leetcode added from the `languageList` query and is overridable in config.
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::{OnceLock, RwLock};
 
-#[derive(Debug, Deserialize, Serialize)]
-struct LanguageInfo {
-    id: u32,
-    name: String,
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct LanguageInfo {
+    pub id: u32,
+    /// The slug, e.g. `cpp`
+    pub name: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct Data {
-    language_list: Vec<LanguageInfo>,
+pub struct Data {
+    pub language_list: Vec<LanguageInfo>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct Languages {
-    data: Data,
+pub struct Languages {
+    pub data: Data,
 }
 
-// Generate the enum for languages
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Hash, Eq)]
-#[serde(rename_all = "lowercase")]
-pub enum Language {
-    Cpp,
-    Java,
-    Python,
-    Python3,
-    Mysql,
-    Mssql,
-    Oraclesql,
-    C,
-    Csharp,
-    Javascript,
-    Ruby,
-    Bash,
-    Swift,
-    Golang,
-    Scala,
-    Html,
-    Pythonml,
-    Kotlin,
-    Rust,
-    Php,
-    Typescript,
-    Racket,
-    Erlang,
-    Elixir,
-    Dart,
-    Pythondata,
-    React,
-    Unknown(u32),
+#[serde(transparent)]
+pub struct Language(Cow<'static, str>);
+
+impl Language {
+    pub fn new(slug: impl Into<String>) -> Self {
+        Self(Cow::Owned(slug.into()))
+    }
+
+    pub fn slug(&self) -> &str {
+        &self.0
+    }
+
+    /// Languages missing from the registry are named after their id.
+    pub fn from_id(id: u32) -> Language {
+        get_registry()
+            .read()
+            .unwrap()
+            .slug_for_id(id)
+            .map(Language::new)
+            .unwrap_or_else(|| Language::new(id.to_string()))
+    }
+
+    pub fn to_id(&self) -> Option<u32> {
+        self.spec()
+            .and_then(|spec| spec.id)
+            .or_else(|| self.slug().parse().ok())
+    }
+
+    pub fn spec(&self) -> Option<LanguageSpec> {
+        get_registry().read().unwrap().get(self.slug()).cloned()
+    }
+
+    pub fn comment_text(&self, input_text: &str) -> String {
+        self.spec()
+            .map(|spec| spec.comment)
+            .unwrap_or_default()
+            .apply(input_text)
+    }
+
+    pub fn get_extension(&self) -> String {
+        self.spec()
+            .map(|spec| spec.extension.into_owned())
+            .unwrap_or_else(|| DEFAULT_EXTENSION.into())
+    }
+
+    /// Command with `{file}` standing for the solution file, it is run without a shell.
+    pub fn formatter(&self) -> Option<String> {
+        self.spec()
+            .and_then(|spec| spec.formatter)
+            .map(Cow::into_owned)
+    }
 }
 
-impl From<u32> for Language {
-    fn from(value: u32) -> Self {
-        match value {
-            0 => Language::Cpp,
-            1 => Language::Java,
-            2 => Language::Python,
-            3 => Language::Mysql,
-            4 => Language::C,
-            5 => Language::Csharp,
-            6 => Language::Javascript,
-            7 => Language::Ruby,
-            8 => Language::Bash,
-            9 => Language::Swift,
-            10 => Language::Golang,
-            11 => Language::Python3,
-            12 => Language::Scala,
-            13 => Language::Kotlin,
-            14 => Language::Mssql,
-            15 => Language::Oraclesql,
-            16 => Language::Html,
-            17 => Language::Pythonml,
-            18 => Language::Rust,
-            19 => Language::Php,
-            20 => Language::Typescript,
-            21 => Language::Racket,
-            22 => Language::Erlang,
-            23 => Language::Elixir,
-            24 => Language::Dart,
-            25 => Language::Pythondata,
-            26 => Language::React,
-            _ => Language::Unknown(value),
-        }
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.slug())
     }
 }
 
-impl From<Language> for u32 {
-    fn from(value: Language) -> Self {
-        match value {
-            Language::Cpp => 0,
-            Language::Java => 1,
-            Language::Python => 2,
-            Language::Mysql => 3,
-            Language::C => 4,
-            Language::Csharp => 5,
-            Language::Javascript => 6,
-            Language::Ruby => 7,
-            Language::Bash => 8,
-            Language::Swift => 9,
-            Language::Golang => 10,
-            Language::Python3 => 11,
-            Language::Scala => 12,
-            Language::Kotlin => 13,
-            Language::Mssql => 14,
-            Language::Oraclesql => 15,
-            Language::Html => 16,
-            Language::Pythonml => 17,
-            Language::Rust => 18,
-            Language::Php => 19,
-            Language::Typescript => 20,
-            Language::Racket => 21,
-            Language::Erlang => 22,
-            Language::Elixir => 23,
-            Language::Dart => 24,
-            Language::Pythondata => 25,
-            Language::React => 26,
-            Language::Unknown(id) => id,
+/// Extension of the solution files of languages nothing is known about.
+const DEFAULT_EXTENSION: &str = "txt";
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentStyle {
+    /// Wraps the whole text, e.g. `["/*", "*/"]`
+    Block(Cow<'static, str>, Cow<'static, str>),
+    /// Prefixes every line, e.g. `"# "`
+    Line(Cow<'static, str>),
+    #[default]
+    None,
+}
+
+impl CommentStyle {
+    pub fn apply(&self, input_text: &str) -> String {
+        match self {
+            CommentStyle::Block(start, end) => format!("{start}\n{input_text}\n{end}"),
+            CommentStyle::Line(prefix) => input_text
+                .lines()
+                .map(|line| format!("{prefix}{line}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            CommentStyle::None => input_text.to_string(),
         }
     }
 }
 
-impl Language {
-    pub fn from_id(id: u32) -> Language {
-        match id {
-            0 => Language::Cpp,
-            1 => Language::Java,
-            2 => Language::Python,
-            3 => Language::Mysql,
-            4 => Language::C,
-            5 => Language::Csharp,
-            6 => Language::Javascript,
-            7 => Language::Ruby,
-            8 => Language::Bash,
-            9 => Language::Swift,
-            10 => Language::Golang,
-            11 => Language::Python3,
-            12 => Language::Scala,
-            13 => Language::Kotlin,
-            14 => Language::Mssql,
-            15 => Language::Oraclesql,
-            16 => Language::Html,
-            17 => Language::Pythonml,
-            18 => Language::Rust,
-            19 => Language::Php,
-            20 => Language::Typescript,
-            21 => Language::Racket,
-            22 => Language::Erlang,
-            23 => Language::Elixir,
-            24 => Language::Dart,
-            25 => Language::Pythondata,
-            26 => Language::React,
-            _ => Language::Unknown(id),
-        }
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LanguageSpec {
+    /// Encoded in the solution file names, `None` until leetcode told it
+    pub id: Option<u32>,
+    pub extension: Cow<'static, str>,
+    pub comment: CommentStyle,
+    pub formatter: Option<Cow<'static, str>>,
+}
+
+/// Config entry of a language, only the given fields replace the known ones.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+#[serde(default)]
+pub struct LanguageOverride {
+    pub id: Option<u32>,
+    pub extension: Option<String>,
+    pub comment: Option<CommentStyle>,
+    pub formatter: Option<String>,
+}
+
+const fn builtin(
+    id: u32,
+    extension: &'static str,
+    comment: CommentStyle,
+    formatter: Option<&'static str>,
+) -> LanguageSpec {
+    let formatter = match formatter {
+        Some(f) => Some(Cow::Borrowed(f)),
+        None => None,
+    };
+    LanguageSpec {
+        id: Some(id),
+        extension: Cow::Borrowed(extension),
+        comment,
+        formatter,
     }
+}
 
-    pub fn to_id(&self) -> u32 {
-        match self {
-            Language::Cpp => 0,
-            Language::Java => 1,
-            Language::Python => 2,
-            Language::Mysql => 3,
-            Language::C => 4,
-            Language::Csharp => 5,
-            Language::Javascript => 6,
-            Language::Ruby => 7,
-            Language::Bash => 8,
-            Language::Swift => 9,
-            Language::Golang => 10,
-            Language::Python3 => 11,
-            Language::Scala => 12,
-            Language::Kotlin => 13,
-            Language::Mssql => 14,
-            Language::Oraclesql => 15,
-            Language::Html => 16,
-            Language::Pythonml => 17,
-            Language::Rust => 18,
-            Language::Php => 19,
-            Language::Typescript => 20,
-            Language::Racket => 21,
-            Language::Erlang => 22,
-            Language::Elixir => 23,
-            Language::Dart => 24,
-            Language::Pythondata => 25,
-            Language::React => 26,
-            Language::Unknown(id) => *id,
+const fn block(start: &'static str, end: &'static str) -> CommentStyle {
+    CommentStyle::Block(Cow::Borrowed(start), Cow::Borrowed(end))
+}
+
+const fn line(prefix: &'static str) -> CommentStyle {
+    CommentStyle::Line(Cow::Borrowed(prefix))
+}
+
+const C_BLOCK: CommentStyle = block("/*", "*/");
+
+const BUILTIN: [(&str, LanguageSpec); 27] = [
+    ("cpp", builtin(0, "cpp", C_BLOCK, None)),
+    ("java", builtin(1, "java", C_BLOCK, None)),
+    ("python", builtin(2, "py", block("'''", "'''"), None)),
+    ("mysql", builtin(3, "sql", line("-- "), None)),
+    ("c", builtin(4, "c", C_BLOCK, None)),
+    ("csharp", builtin(5, "cs", line("// "), None)),
+    ("javascript", builtin(6, "js", C_BLOCK, None)),
+    ("ruby", builtin(7, "rb", block("=begin", "=end"), None)),
+    ("bash", builtin(8, "sh", line("# "), None)),
+    ("swift", builtin(9, "swift", C_BLOCK, None)),
+    (
+        "golang",
+        builtin(10, "go", C_BLOCK, Some("gofmt -w {file}")),
+    ),
+    ("python3", builtin(11, "py", block("'''", "'''"), None)),
+    ("scala", builtin(12, "scala", C_BLOCK, None)),
+    ("kotlin", builtin(13, "kt", C_BLOCK, None)),
+    ("mssql", builtin(14, "sql", line("-- "), None)),
+    ("oraclesql", builtin(15, "sql", line("-- "), None)),
+    ("html", builtin(16, "html", block("<!--", "-->"), None)),
+    ("pythonml", builtin(17, "py", line("# "), None)),
+    (
+        "rust",
+        builtin(18, "rs", C_BLOCK, Some("rustfmt --edition 2021 {file}")),
+    ),
+    ("php", builtin(19, "php", line("// "), None)),
+    ("typescript", builtin(20, "ts", line("// "), None)),
+    ("racket", builtin(21, "rkt", line("; "), None)),
+    ("erlang", builtin(22, "erl", line("% "), None)),
+    ("elixir", builtin(23, "ex", line("# "), None)),
+    ("dart", builtin(24, "dart", line("// "), None)),
+    ("pythondata", builtin(25, "py", line("# "), None)),
+    ("react", builtin(26, "jsx", line("// "), None)),
+];
+
+pub static REGISTRY: OnceLock<RwLock<LanguageRegistry>> = OnceLock::new();
+
+pub fn get_registry() -> &'static RwLock<LanguageRegistry> {
+    REGISTRY.get_or_init(|| RwLock::new(LanguageRegistry::default()))
+}
+
+/// Overrides from config, applied again whenever the registry learns new languages.
+pub fn init(overrides: HashMap<String, LanguageOverride>) {
+    get_registry().write().unwrap().set_overrides(overrides);
+}
+
+/// Languages keyed by slug.
+#[derive(Debug, Clone)]
+pub struct LanguageRegistry {
+    specs: HashMap<String, LanguageSpec>,
+    overrides: HashMap<String, LanguageOverride>,
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        Self {
+            specs: BUILTIN
+                .into_iter()
+                .map(|(slug, spec)| (slug.to_string(), spec))
+                .collect(),
+            overrides: HashMap::new(),
         }
     }
+}
 
-    pub fn comment_text(&self, input_text: &str) -> String {
-        let (comment_start, comment_end) = match self {
-            Language::Cpp
-            | Language::C
-            | Language::Scala
-            | Language::Java
-            | Language::Javascript
-            | Language::Swift
-            | Language::Golang
-            | Language::Rust
-            | Language::Kotlin => ("/*\n", "\n*/"),
-            Language::Python | Language::Python3 => ("'''\n", "\n'''"),
-            Language::Mysql | Language::Mssql | Language::Oraclesql => ("-- ", ""),
-            Language::Csharp => ("// ", ""),
-            Language::Ruby => ("=begin\n", "\n=end"),
-            Language::Bash => ("# ", ""),
-            Language::Html => ("<!--\n", "\n-->"),
-            Language::Pythonml => ("# ", ""),
-            // => ("// ", ""),
-            Language::Php => ("// ", ""),
-            Language::Typescript => ("// ", ""),
-            Language::Racket => ("; ", ""),
-            Language::Erlang => ("% ", ""),
-            Language::Elixir => ("# ", ""),
-            Language::Dart => ("// ", ""),
-            Language::Pythondata => ("# ", ""),
-            Language::React => ("// ", ""),
-            Language::Unknown(_) => ("", ""),
-        };
+impl LanguageRegistry {
+    pub fn get(&self, slug: &str) -> Option<&LanguageSpec> {
+        self.specs.get(slug)
+    }
 
-        match self {
-            Language::C
-            | Language::Html
-            | Language::Cpp
-            | Language::Python
-            | Language::Python3
-            | Language::Ruby
-            | Language::Javascript
-            | Language::Scala
-            | Language::Java
-            | Language::Swift
-            | Language::Golang
-            | Language::Kotlin
-            | Language::Rust => {
-                format!("{}{}{}", comment_start, input_text, comment_end)
-            }
-            _ => {
-                let commented_lines: Vec<String> = input_text
-                    .lines()
-                    .map(|line| format!("{}{}", comment_start, line))
-                    .collect();
+    pub fn slug_for_id(&self, id: u32) -> Option<&str> {
+        self.specs
+            .iter()
+            .find(|(_, spec)| spec.id == Some(id))
+            .map(|(slug, _)| slug.as_str())
+    }
 
-                commented_lines.join("\n")
-            }
-        }
+    pub fn set_overrides(&mut self, overrides: HashMap<String, LanguageOverride>) {
+        self.overrides = overrides;
+        self.apply_overrides();
     }
 
-    pub fn get_extension(&self) -> &str {
-        match self {
-            Language::Cpp => "cpp",
-            Language::Java => "java",
-            Language::Python => "py",
-            Language::Python3 => "py",
-            Language::Mysql => "sql",
-            Language::Mssql => "sql",
-            Language::Oraclesql => "sql",
-            Language::C => "c",
-            Language::Csharp => "cs",
-            Language::Javascript => "js",
-            Language::Ruby => "rb",
-            Language::Bash => "sh",
-            Language::Swift => "swift",
-            Language::Golang => "go",
-            Language::Scala => "scala",
-            Language::Html => "html",
-            Language::Pythonml => "py",
-            Language::Kotlin => "kt",
-            Language::Rust => "rs",
-            Language::Php => "php",
-            Language::Typescript => "ts",
-            Language::Racket => "rkt",
-            Language::Erlang => "erl",
-            Language::Elixir => "ex",
-            Language::Dart => "dart",
-            Language::Pythondata => "py",
-            Language::React => "jsx",
-            Language::Unknown(_) => "",
+    /// Takes the ids leetcode reports, languages not known so far are added
+    /// with a plain text extension until configured.
+    pub fn merge_language_list(&mut self, languages: &[LanguageInfo]) {
+        for language in languages {
+            self.specs
+                .entry(language.name.clone())
+                .and_modify(|spec| spec.id = Some(language.id))
+                .or_insert_with(|| LanguageSpec {
+                    id: Some(language.id),
+                    extension: Cow::Borrowed(DEFAULT_EXTENSION),
+                    comment: CommentStyle::None,
+                    formatter: None,
+                });
         }
+        self.apply_overrides();
     }
-}
 
-impl Display for Language {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let k = match self {
-            Language::Cpp => "cpp".to_string(),
-            Language::Java => "java".to_string(),
-            Language::Python => "python".to_string(),
-            Language::Python3 => "python3".to_string(),
-            Language::Mysql => "mysql".to_string(),
-            Language::Mssql => "mssql".to_string(),
-            Language::Oraclesql => "oraclesql".to_string(),
-            Language::C => "c".to_string(),
-            Language::Csharp => "csharp".to_string(),
-            Language::Javascript => "javascript".to_string(),
-            Language::Ruby => "ruby".to_string(),
-            Language::Bash => "bash".to_string(),
-            Language::Swift => "swift".to_string(),
-            Language::Golang => "golang".to_string(),
-            Language::Scala => "scala".to_string(),
-            Language::Html => "html".to_string(),
-            Language::Pythonml => "pythonml".to_string(),
-            Language::Kotlin => "kotlin".to_string(),
-            Language::Rust => "rust".to_string(),
-            Language::Php => "php".to_string(),
-            Language::Typescript => "typescript".to_string(),
-            Language::Racket => "racket".to_string(),
-            Language::Erlang => "erlang".to_string(),
-            Language::Elixir => "elixir".to_string(),
-            Language::Dart => "dart".to_string(),
-            Language::Pythondata => "pythondata".to_string(),
-            Language::React => "react".to_string(),
-            Language::Unknown(id) => format!("{}", id),
-        };
-        f.write_str(k.as_str())
+    fn apply_overrides(&mut self) {
+        for (slug, o) in self.overrides.iter() {
+            let spec = self
+                .specs
+                .entry(slug.clone())
+                .or_insert_with(|| LanguageSpec {
+                    id: None,
+                    extension: Cow::Borrowed(DEFAULT_EXTENSION),
+                    comment: CommentStyle::None,
+                    formatter: None,
+                });
+            if o.id.is_some() {
+                spec.id = o.id;
+            }
+            if let Some(extension) = &o.extension {
+                spec.extension = Cow::Owned(extension.clone());
+            }
+            if let Some(comment) = &o.comment {
+                spec.comment = comment.clone();
+            }
+            if let Some(formatter) = &o.formatter {
+                // an empty command turns the default formatter off
+                spec.formatter = (!formatter.is_empty()).then(|| Cow::Owned(formatter.clone()));
+            }
+        }
     }
 }
 
@@ -327,55 +300,61 @@ mod tests {
         let test_cases = [
             // Test for C++
             (
-                Language::Cpp,
+                Language::new("cpp"),
                 "This is a single-line comment.",
                 "/*\nThis is a single-line comment.\n*/",
             ),
             (
-                Language::Cpp,
+                Language::new("cpp"),
                 "This is a multi-line text.\nIt can have multiple lines.",
                 "/*\nThis is a multi-line text.\nIt can have multiple lines.\n*/",
             ),
             (
-                Language::Python,
+                Language::new("python"),
                 "This is a single-line comment.",
                 "'''\nThis is a single-line comment.\n'''",
             ),
             (
-                Language::Python,
+                Language::new("python"),
                 "This is a multi-line text.\nIt can have multiple lines.",
                 "'''\nThis is a multi-line text.\nIt can have multiple lines.\n'''",
             ),
             // Test for C
             (
-                Language::C,
+                Language::new("c"),
                 "This is a single-line comment.",
                 "/*\nThis is a single-line comment.\n*/",
             ),
             (
-                Language::C,
+                Language::new("c"),
                 "This is a multi-line text.\nIt can have multiple lines.",
                 "/*\nThis is a multi-line text.\nIt can have multiple lines.\n*/",
             ),
             // Test for HTML
             (
-                Language::Html,
+                Language::new("html"),
                 "This is a single-line comment.",
                 "<!--\nThis is a single-line comment.\n-->",
             ),
             (
-                Language::Html,
+                Language::new("html"),
                 "This is a multi-line text.\nIt can have multiple lines.",
                 "<!--\nThis is a multi-line text.\nIt can have multiple lines.\n-->",
             ),
+            // Test for line comments
+            (
+                Language::new("bash"),
+                "This is a multi-line text.\nIt can have multiple lines.",
+                "# This is a multi-line text.\n# It can have multiple lines.",
+            ),
             // Test for Unknown language
             (
-                Language::Unknown(999),
+                Language::from_id(999),
                 "This is a single-line comment.",
                 "This is a single-line comment.",
             ),
             (
-                Language::Unknown(999),
+                Language::from_id(999),
                 "This is a multi-line text.\nIt can have multiple lines.",
                 "This is a multi-line text.\nIt can have multiple lines.",
             ),
@@ -386,62 +365,81 @@ mod tests {
         }
     }
 
-    use std::collections::HashMap;
     #[test]
-    fn test() {
-        // JSON data as a string
+    fn test_builtin_ids_round_trip() {
+        for (slug, spec) in BUILTIN {
+            let language = Language::from_id(spec.id.unwrap());
+            assert_eq!(language.slug(), slug);
+            assert_eq!(language.to_id(), spec.id);
+        }
+        assert_eq!(Language::from_id(999).to_string(), "999");
+        assert_eq!(Language::from_id(999).to_id(), Some(999));
+    }
+
+    #[test]
+    fn test_language_list_and_overrides_are_merged() {
         let json_data = r#"
         {
             "data": {
                 "languageList": [
                     { "id": 0, "name": "cpp" },
-                    { "id": 1, "name": "java" },
-                    { "id": 2, "name": "python" },
                     { "id": 11, "name": "python3" },
-                    { "id": 3, "name": "mysql" },
-                    { "id": 14, "name": "mssql" },
-                    { "id": 15, "name": "oraclesql" },
-                    { "id": 4, "name": "c" },
-                    { "id": 5, "name": "csharp" },
-                    { "id": 6, "name": "javascript" },
-                    { "id": 7, "name": "ruby" },
-                    { "id": 8, "name": "bash" },
-                    { "id": 9, "name": "swift" },
-                    { "id": 10, "name": "golang" },
-                    { "id": 12, "name": "scala" },
-                    { "id": 16, "name": "html" },
-                    { "id": 17, "name": "pythonml" },
-                    { "id": 13, "name": "kotlin" },
-                    { "id": 18, "name": "rust" },
-                    { "id": 19, "name": "php" },
-                    { "id": 20, "name": "typescript" },
-                    { "id": 21, "name": "racket" },
-                    { "id": 22, "name": "erlang" },
-                    { "id": 23, "name": "elixir" },
-                    { "id": 24, "name": "dart" },
-                    { "id": 25, "name": "pythondata" },
-                    { "id": 26, "name": "react" }
+                    { "id": 27, "name": "cangjie" }
                 ]
             }
         }
     "#;
-
-        // Parse JSON data into the Languages struct
         let languages: Languages = serde_json::from_str(json_data).unwrap();
 
-        // Extract the languageList
-        let language_list = languages.data.language_list;
+        let mut registry = LanguageRegistry::default();
+        registry.set_overrides(HashMap::from([
+            (
+                "cangjie".to_string(),
+                LanguageOverride {
+                    extension: Some("cj".into()),
+                    comment: Some(CommentStyle::Line("// ".into())),
+                    ..Default::default()
+                },
+            ),
+            (
+                "rust".to_string(),
+                LanguageOverride {
+                    formatter: Some("".into()),
+                    ..Default::default()
+                },
+            ),
+        ]));
+        // the override is known before leetcode tells the id
+        assert_eq!(registry.get("cangjie").unwrap().id, None);
 
-        // Create a HashMap to store Language enums by id
-        let mut language_map: HashMap<u32, Language> = HashMap::new();
-        for lang_info in language_list {
-            language_map.insert(lang_info.id, Language::from_id(lang_info.id));
-        }
+        registry.merge_language_list(&languages.data.language_list);
+        assert_eq!(registry.slug_for_id(27), Some("cangjie"));
+        let cangjie = registry.get("cangjie").unwrap();
+        assert_eq!(cangjie.extension, "cj");
+        assert_eq!(cangjie.comment.apply("a\nb"), "// a\n// b");
+        assert_eq!(registry.get("rust").unwrap().formatter, None);
+        assert_eq!(registry.get("python3").unwrap().extension, "py");
+    }
 
-        // Example: Accessing the language by id
-        let id_to_find = 2; // Example: "python"
-        if let Some(lang) = language_map.get(&id_to_find) {
-            println!("Language with id {}: {:?}", id_to_find, lang);
+    #[test]
+    fn test_comment_style_from_config() {
+        #[derive(Deserialize)]
+        struct Entries {
+            languages: HashMap<String, LanguageOverride>,
         }
+        let entries: Entries = serde_json::from_str(
+            r#"{"languages": {
+                "kotlin": {"comment": {"line": "// "}, "formatter": "ktfmt {file}"},
+                "scala": {"comment": {"block": ["/**", "*/"]}}
+            }}"#,
+        )
+        .unwrap();
+        let kotlin = &entries.languages["kotlin"];
+        assert_eq!(kotlin.comment, Some(CommentStyle::Line("// ".into())));
+        assert_eq!(kotlin.formatter.as_deref(), Some("ktfmt {file}"));
+        assert_eq!(
+            entries.languages["scala"].comment,
+            Some(CommentStyle::Block("/**".into(), "*/".into()))
+        );
     }
 }
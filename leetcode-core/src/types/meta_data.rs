@@ -0,0 +1,260 @@
+//! Typed view of the `metaData` json leetcode attaches to every question. It
+//! describes the signature the solution has to implement.
+use serde::Deserialize;
+use std::fmt::Display;
+
+/// Types as they are spelled by leetcode, e.g. `integer[]`, `list<list<string>>`, `ListNode`.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum ParamType {
+    Integer,
+    Long,
+    Double,
+    Boolean,
+    Character,
+    String,
+    Void,
+    ListNode,
+    TreeNode,
+    Array(Box<ParamType>),
+    List(Box<ParamType>),
+    /// Anything question specific, e.g. `Node`, `NestedInteger`
+    Other(String),
+}
+
+impl From<&str> for ParamType {
+    fn from(value: &str) -> Self {
+        let value = value.trim();
+        if let Some(inner) = value.strip_suffix("[]") {
+            return ParamType::Array(Box::new(inner.into()));
+        }
+        if let Some(inner) = value
+            .strip_prefix("list<")
+            .and_then(|v| v.strip_suffix('>'))
+        {
+            return ParamType::List(Box::new(inner.into()));
+        }
+        match value {
+            "integer" => ParamType::Integer,
+            "long" => ParamType::Long,
+            "double" => ParamType::Double,
+            "boolean" => ParamType::Boolean,
+            "character" => ParamType::Character,
+            "string" => ParamType::String,
+            "void" => ParamType::Void,
+            "ListNode" => ParamType::ListNode,
+            "TreeNode" => ParamType::TreeNode,
+            other => ParamType::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for ParamType {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl Display for ParamType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamType::Integer => write!(f, "integer"),
+            ParamType::Long => write!(f, "long"),
+            ParamType::Double => write!(f, "double"),
+            ParamType::Boolean => write!(f, "boolean"),
+            ParamType::Character => write!(f, "character"),
+            ParamType::String => write!(f, "string"),
+            ParamType::Void => write!(f, "void"),
+            ParamType::ListNode => write!(f, "ListNode"),
+            ParamType::TreeNode => write!(f, "TreeNode"),
+            ParamType::Array(inner) => write!(f, "{inner}[]"),
+            ParamType::List(inner) => write!(f, "list<{inner}>"),
+            ParamType::Other(other) => write!(f, "{other}"),
+        }
+    }
+}
+
+impl ParamType {
+    /// Number of array/list levels, `integer[][]` has 2.
+    pub fn depth(&self) -> usize {
+        match self {
+            ParamType::Array(inner) | ParamType::List(inner) => 1 + inner.depth(),
+            _ => 0,
+        }
+    }
+
+    /// Innermost type of an array/list, the type itself otherwise.
+    pub fn element(&self) -> &ParamType {
+        match self {
+            ParamType::Array(inner) | ParamType::List(inner) => inner.element(),
+            other => other,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct Param {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub param_type: ParamType,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ReturnType {
+    #[serde(rename = "type")]
+    pub return_type: ParamType,
+    /// Fixed length of the returned array, when leetcode knows it
+    #[serde(default)]
+    pub size: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct FunctionSignature {
+    pub name: String,
+    #[serde(default)]
+    pub params: Vec<Param>,
+    #[serde(rename = "return")]
+    pub return_type: ReturnType,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct Constructor {
+    #[serde(default)]
+    pub params: Vec<Param>,
+}
+
+/// Signature of the "design" questions, where the testcase is a list of method calls.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ClassSignature {
+    #[serde(rename = "classname")]
+    pub class_name: String,
+    pub constructor: Constructor,
+    pub methods: Vec<FunctionSignature>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetaData {
+    Function(FunctionSignature),
+    SystemDesign(ClassSignature),
+    /// SQL questions, they come with the table definitions instead of a signature
+    Database,
+    Shell,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawMetaData {
+    SystemDesign(ClassSignature),
+    Function(FunctionSignature),
+    Other {
+        #[serde(default)]
+        database: bool,
+        #[serde(default)]
+        shell: bool,
+    },
+}
+
+impl<'de> Deserialize<'de> for MetaData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match RawMetaData::deserialize(deserializer)? {
+            RawMetaData::SystemDesign(class) => MetaData::SystemDesign(class),
+            RawMetaData::Function(function) => MetaData::Function(function),
+            RawMetaData::Other { database: true, .. } => MetaData::Database,
+            RawMetaData::Other { shell: true, .. } => MetaData::Shell,
+            RawMetaData::Other { .. } => {
+                return Err(serde::de::Error::custom("unrecognized question metaData"))
+            }
+        })
+    }
+}
+
+impl std::str::FromStr for MetaData {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_meta_data() {
+        let meta_data: MetaData = r#"{
+            "name": "mergeKLists",
+            "params": [
+                {"name": "lists", "type": "ListNode[]"},
+                {"name": "board", "type": "character[][]"},
+                {"name": "words", "type": "list<list<string>>"}
+            ],
+            "return": {"type": "ListNode"}
+        }"#
+        .parse()
+        .unwrap();
+        let MetaData::Function(function) = meta_data else {
+            panic!("expected a function signature");
+        };
+        assert_eq!(function.name, "mergeKLists");
+        assert_eq!(
+            function.params[0].param_type,
+            ParamType::Array(Box::new(ParamType::ListNode))
+        );
+        let board = &function.params[1].param_type;
+        assert_eq!(board.depth(), 2);
+        assert_eq!(board.element(), &ParamType::Character);
+        assert_eq!(board.to_string(), "character[][]");
+        assert_eq!(
+            function.params[2].param_type.to_string(),
+            "list<list<string>>"
+        );
+        assert_eq!(function.return_type.return_type, ParamType::ListNode);
+    }
+
+    #[test]
+    fn test_system_design_meta_data() {
+        let meta_data: MetaData = r#"{
+            "classname": "LRUCache",
+            "constructor": {"params": [{"type": "integer", "name": "capacity"}]},
+            "methods": [
+                {"params": [{"type": "integer", "name": "key"}], "name": "get", "return": {"type": "integer"}},
+                {"params": [{"type": "integer", "name": "key"}, {"type": "integer", "name": "value"}], "name": "put", "return": {"type": "void"}}
+            ],
+            "return": {"type": "boolean"},
+            "systemdesign": true
+        }"#
+        .parse()
+        .unwrap();
+        let MetaData::SystemDesign(class) = meta_data else {
+            panic!("expected a class signature");
+        };
+        assert_eq!(class.class_name, "LRUCache");
+        assert_eq!(class.constructor.params[0].name, "capacity");
+        assert_eq!(class.methods[1].name, "put");
+        assert_eq!(class.methods[1].return_type.return_type, ParamType::Void);
+    }
+
+    #[test]
+    fn test_database_and_shell_meta_data() {
+        let database: MetaData = r#"{"mysql": ["Create table Person (id int)"], "database": true}"#
+            .parse()
+            .unwrap();
+        assert_eq!(database, MetaData::Database);
+        let shell: MetaData = r#"{"shell": true}"#.parse().unwrap();
+        assert_eq!(shell, MetaData::Shell);
+        assert!(r#"{"manual": true}"#.parse::<MetaData>().is_err());
+    }
+
+    #[test]
+    fn test_question_specific_types_are_kept() {
+        assert_eq!(
+            ParamType::from("NestedInteger"),
+            ParamType::Other("NestedInteger".into())
+        );
+        assert_eq!(ParamType::from("long").to_string(), "long");
+    }
+}
@@ -1,9 +1,47 @@
 pub mod console_panel_config;
+pub mod daily_challenge_calendar;
 pub mod daily_coding_challenge;
 pub mod editor_data;
+pub mod favorite;
 pub mod language;
+pub mod meta_data;
 pub mod problemset_question_list;
 pub mod question_content;
+pub mod question_detail;
+pub mod question_votes;
 pub(crate) mod run;
 pub mod run_submit_response;
+pub mod study_plan;
+pub mod submission_details;
+pub mod submission_list;
 pub mod submit;
+pub mod user_progress;
+pub mod user_status;
+
+/// Leetcode sends some of the nested objects as json encoded strings.
+pub(crate) fn from_json_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    use serde::Deserialize;
+    Option::<String>::deserialize(deserializer)?
+        .filter(|raw| !raw.is_empty())
+        .map(|raw| serde_json::from_str(&raw).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// leetcode.cn sends the localized html next to the original one, which is
+/// preferred whenever it is there.
+pub(crate) fn localized<'a>(content: &'a str, translated: Option<&'a str>) -> &'a str {
+    match translated {
+        Some(translated) if !translated.is_empty() => translated,
+        _ => content,
+    }
+}
+
+/// Share of the votes that are likes, `None` when nobody voted yet.
+pub fn like_ratio(likes: u32, dislikes: u32) -> Option<f64> {
+    let votes = likes + dislikes;
+    (votes > 0).then(|| likes as f64 / votes as f64)
+}
@@ -20,6 +20,9 @@ pub struct Question {
     pub paid_only: bool,
     pub status: Option<String>,
     pub title: String,
+    /// Only populated by leetcode.cn
+    #[serde(default)]
+    pub translated_title: Option<String>,
     pub title_slug: String,
     pub has_solution: Option<bool>,
     pub has_video_solution: Option<bool>,
@@ -105,7 +108,7 @@ mod tests {
         assert_eq!(question.freq_bar, None);
         assert_eq!(question.frontend_question_id, "6".to_string());
         assert_eq!(question.is_favor, Some(false));
-        assert_eq!(question.paid_only, false);
+        assert!(!question.paid_only);
         assert_eq!(question.status, Some("ac".to_string()));
         assert_eq!(question.title, ("Zigzag Conversion".to_string()));
         assert_eq!(question.title_slug, ("zigzag-conversion".to_string()));
@@ -120,5 +123,44 @@ mod tests {
 
         assert_eq!(question.has_solution, Some(true));
         assert_eq!(question.has_video_solution, Some(false));
+        assert_eq!(question.translated_title, None);
+    }
+
+    #[test]
+    fn test_cn_json_deserialization() {
+        let json = r#"{
+            "data": {
+                "problemsetQuestionList": {
+                    "total": 3000,
+                    "questions": [
+                        {
+                            "acRate": 53.2,
+                            "difficulty": "Easy",
+                            "freqBar": null,
+                            "frontendQuestionId": "1",
+                            "isFavor": false,
+                            "paidOnly": false,
+                            "status": null,
+                            "title": "Two Sum",
+                            "translatedTitle": "两数之和",
+                            "titleSlug": "two-sum",
+                            "topicTags": [
+                                {
+                                    "name": "Array",
+                                    "id": "VG9waWNUYWdOb2RlOjU=",
+                                    "slug": "array"
+                                }
+                            ]
+                        }
+                    ]
+                }
+            }
+        }"#;
+
+        let root: Root = serde_json::from_str(json).unwrap();
+        let question = &root.data.problemset_question_list.questions[0];
+        assert_eq!(question.translated_title, Some("两数之和".to_string()));
+        assert_eq!(question.has_solution, None);
+        assert_eq!(question.has_video_solution, None);
     }
 }
@@ -8,6 +8,9 @@ pub struct QueryQuestionContent {
 #[serde(rename_all = "camelCase")]
 pub struct QuestionContent {
     pub content: String,
+    /// Only populated by leetcode.cn
+    #[serde(default)]
+    pub translated_content: Option<String>,
     pub title_slug: String,
 }
 
@@ -17,9 +20,48 @@ pub struct Data {
 }
 
 impl QuestionContent {
+    pub fn get_content(&self) -> &str {
+        super::localized(&self.content, self.translated_content.as_deref())
+    }
+
     pub fn html_to_text(&self) -> String {
-        let string = self.content.as_str();
+        let string = self.get_content();
         let s: String = parse_html(string);
         s.lines().collect::<Vec<&str>>().join("\n")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translated_content_is_preferred_when_present() {
+        let json = r#"{
+            "data": {
+                "question": {
+                    "content": "<p>Given an array</p>",
+                    "translatedContent": "<p>给定一个整数数组</p>",
+                    "titleSlug": "two-sum"
+                }
+            }
+        }"#;
+        let data: Data = serde_json::from_str(json).unwrap();
+        assert_eq!(data.data.question.get_content(), "<p>给定一个整数数组</p>");
+    }
+
+    #[test]
+    fn test_content_is_used_when_translation_is_missing() {
+        let json = r#"{
+            "data": {
+                "question": {
+                    "content": "<p>Given an array</p>",
+                    "translatedContent": null,
+                    "titleSlug": "two-sum"
+                }
+            }
+        }"#;
+        let data: Data = serde_json::from_str(json).unwrap();
+        assert_eq!(data.data.question.get_content(), "<p>Given an array</p>");
+    }
+}
@@ -0,0 +1,246 @@
+use super::from_json_str;
+use super::meta_data::MetaData;
+use html2md::parse_html;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicTag {
+    pub name: String,
+    pub slug: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarQuestion {
+    pub title: String,
+    pub title_slug: String,
+    pub difficulty: String,
+    #[serde(default)]
+    pub translated_title: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionStats {
+    pub total_accepted: String,
+    pub total_submission: String,
+    pub total_accepted_raw: u64,
+    pub total_submission_raw: u64,
+    pub ac_rate: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyTag {
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub times_encountered: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionDetail {
+    pub question_id: String,
+    pub question_frontend_id: String,
+    pub title: String,
+    pub title_slug: String,
+    pub content: Option<String>,
+    /// Only populated by leetcode.cn
+    #[serde(default)]
+    pub translated_content: Option<String>,
+    pub difficulty: String,
+    pub is_paid_only: bool,
+    pub likes: u32,
+    pub dislikes: u32,
+    #[serde(default)]
+    pub hints: Vec<String>,
+    #[serde(default, deserialize_with = "from_json_str")]
+    pub similar_questions: Option<Vec<SimilarQuestion>>,
+    #[serde(default, deserialize_with = "from_json_str")]
+    pub stats: Option<QuestionStats>,
+    pub sample_test_case: Option<String>,
+    /// Json describing the function signature, see [`QuestionDetail::get_meta_data`]
+    pub meta_data: Option<String>,
+    /// Keyed by the period the question was asked in, `null` for non premium users
+    #[serde(default, deserialize_with = "from_json_str")]
+    pub company_tag_stats: Option<BTreeMap<String, Vec<CompanyTag>>>,
+    #[serde(default)]
+    pub topic_tags: Vec<TopicTag>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QueryQuestionDetail {
+    pub question: QuestionDetail,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    pub data: QueryQuestionDetail,
+}
+
+impl QuestionDetail {
+    pub fn get_content(&self) -> &str {
+        super::localized(
+            self.content.as_deref().unwrap_or_default(),
+            self.translated_content.as_deref(),
+        )
+    }
+
+    pub fn get_meta_data(&self) -> Option<serde_json::Result<MetaData>> {
+        self.meta_data.as_deref().map(str::parse)
+    }
+
+    /// `None` when nobody voted yet.
+    pub fn like_ratio(&self) -> Option<f64> {
+        super::like_ratio(self.likes, self.dislikes)
+    }
+
+    /// Companies summed over all periods, most frequent first.
+    pub fn get_companies(&self) -> Vec<CompanyTag> {
+        let mut companies: BTreeMap<&str, CompanyTag> = BTreeMap::new();
+        for tag in self
+            .company_tag_stats
+            .iter()
+            .flat_map(|s| s.values().flatten())
+        {
+            companies
+                .entry(tag.slug.as_str())
+                .and_modify(|c| c.times_encountered += tag.times_encountered)
+                .or_insert_with(|| tag.clone());
+        }
+        let mut companies: Vec<CompanyTag> = companies.into_values().collect();
+        companies.sort_by_key(|c| std::cmp::Reverse(c.times_encountered));
+        companies
+    }
+
+    pub fn get_display_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "{}  👍 {}  👎 {}",
+            self.difficulty, self.likes, self.dislikes
+        )];
+        if let Some(stats) = &self.stats {
+            lines.push(format!(
+                "Accepted {} / Submissions {} ({})",
+                stats.total_accepted, stats.total_submission, stats.ac_rate
+            ));
+        }
+        if !self.topic_tags.is_empty() {
+            let topics: Vec<&str> = self.topic_tags.iter().map(|t| t.name.as_str()).collect();
+            lines.push(format!("Topics: {}", topics.join(", ")));
+        }
+        let companies = self.get_companies();
+        if !companies.is_empty() {
+            let companies: Vec<String> = companies
+                .iter()
+                .map(|c| format!("{} ({})", c.name, c.times_encountered))
+                .collect();
+            lines.push(format!("Companies: {}", companies.join(", ")));
+        }
+
+        lines.push("".into());
+        lines.extend(
+            parse_html(self.get_content())
+                .lines()
+                .map(|l| l.to_string()),
+        );
+
+        if !self.hints.is_empty() {
+            lines.push("".into());
+            lines.push("## Hints".into());
+            for (i, hint) in self.hints.iter().enumerate() {
+                lines.push(format!("{}. {}", i + 1, parse_html(hint).trim()));
+            }
+        }
+
+        let similar = self.similar_questions.as_deref().unwrap_or_default();
+        if !similar.is_empty() {
+            lines.push("".into());
+            lines.push("## Similar Questions".into());
+            for q in similar {
+                let title = q.translated_title.as_deref().unwrap_or(q.title.as_str());
+                lines.push(format!("- {} ({})", title, q.difficulty));
+            }
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_question_detail_deserialization() {
+        let json = r#"{
+            "data": {
+                "question": {
+                    "questionId": "1",
+                    "questionFrontendId": "1",
+                    "title": "Two Sum",
+                    "titleSlug": "two-sum",
+                    "content": "<p>Given an array</p>",
+                    "translatedContent": null,
+                    "difficulty": "Easy",
+                    "isPaidOnly": false,
+                    "likes": 300,
+                    "dislikes": 100,
+                    "hints": ["<p>Use a hash map</p>"],
+                    "similarQuestions": "[{\"title\": \"3Sum\", \"titleSlug\": \"3sum\", \"difficulty\": \"Medium\", \"translatedTitle\": null}]",
+                    "stats": "{\"totalAccepted\": \"12.5M\", \"totalSubmission\": \"23.9M\", \"totalAcceptedRaw\": 12503938, \"totalSubmissionRaw\": 23915563, \"acRate\": \"52.3%\"}",
+                    "sampleTestCase": "[2,7,11,15]\n9",
+                    "metaData": "{\"name\": \"twoSum\"}",
+                    "companyTagStats": "{\"1\": [{\"taggedByAdmin\": false, \"name\": \"Google\", \"slug\": \"google\", \"timesEncountered\": 5}], \"2\": [{\"taggedByAdmin\": false, \"name\": \"Google\", \"slug\": \"google\", \"timesEncountered\": 3}, {\"taggedByAdmin\": false, \"name\": \"Amazon\", \"slug\": \"amazon\", \"timesEncountered\": 4}]}",
+                    "topicTags": [{"name": "Array", "slug": "array"}]
+                }
+            }
+        }"#;
+        let question = serde_json::from_str::<Data>(json).unwrap().data.question;
+        assert_eq!(question.like_ratio(), Some(0.75));
+        assert_eq!(
+            question.stats.as_ref().unwrap().total_accepted_raw,
+            12503938
+        );
+        assert_eq!(
+            question.similar_questions.as_ref().unwrap()[0].title_slug,
+            "3sum"
+        );
+        assert_eq!(question.sample_test_case.as_deref(), Some("[2,7,11,15]\n9"));
+
+        let companies = question.get_companies();
+        assert_eq!(companies[0].slug, "google");
+        assert_eq!(companies[0].times_encountered, 8);
+        assert_eq!(companies[1].slug, "amazon");
+    }
+
+    #[test]
+    fn test_company_stats_are_optional() {
+        let json = r#"{
+            "data": {
+                "question": {
+                    "questionId": "1",
+                    "questionFrontendId": "1",
+                    "title": "Two Sum",
+                    "titleSlug": "two-sum",
+                    "content": null,
+                    "difficulty": "Easy",
+                    "isPaidOnly": false,
+                    "likes": 0,
+                    "dislikes": 0,
+                    "hints": [],
+                    "similarQuestions": "[]",
+                    "stats": null,
+                    "sampleTestCase": null,
+                    "metaData": null,
+                    "companyTagStats": null
+                }
+            }
+        }"#;
+        let question = serde_json::from_str::<Data>(json).unwrap().data.question;
+        assert_eq!(question.like_ratio(), None);
+        assert!(question.get_companies().is_empty());
+        assert_eq!(question.get_content(), "");
+    }
+}
@@ -0,0 +1,60 @@
+use super::from_json_str;
+use super::question_detail::QuestionStats;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionVotes {
+    pub question_frontend_id: String,
+    pub likes: u32,
+    pub dislikes: u32,
+    #[serde(default, deserialize_with = "from_json_str")]
+    pub stats: Option<QuestionStats>,
+}
+
+impl QuestionVotes {
+    /// `None` when nobody voted yet.
+    pub fn like_ratio(&self) -> Option<f64> {
+        super::like_ratio(self.likes, self.dislikes)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    /// Keyed by the alias of each question, `null` for the slugs leetcode does not know
+    pub data: HashMap<String, Option<QuestionVotes>>,
+}
+
+impl Data {
+    pub fn into_votes(self) -> Vec<QuestionVotes> {
+        self.data.into_values().flatten().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aliased_votes_deserialization() {
+        let json = r#"{
+            "data": {
+                "q0": {
+                    "questionFrontendId": "1",
+                    "likes": 300,
+                    "dislikes": 100,
+                    "stats": "{\"totalAccepted\": \"12.5M\", \"totalSubmission\": \"23.9M\", \"totalAcceptedRaw\": 12503938, \"totalSubmissionRaw\": 23915563, \"acRate\": \"52.3%\"}"
+                },
+                "q1": null
+            }
+        }"#;
+        let votes = serde_json::from_str::<Data>(json).unwrap().into_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].like_ratio(), Some(0.75));
+        assert_eq!(
+            votes[0].stats.as_ref().unwrap().total_submission_raw,
+            23915563
+        );
+    }
+}
@@ -12,7 +12,40 @@ pub struct RunCodeRequest {
     pub slug: String,
 }
 
+/// Input lines of a single testcase, as leetcode shows them in the console.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestCase {
+    pub input: String,
+    /// Written by the user rather than taken from the question examples
+    pub is_custom: bool,
+}
+
+impl TestCase {
+    pub fn sample(input: impl Into<String>) -> Self {
+        Self {
+            input: input.into(),
+            is_custom: false,
+        }
+    }
+
+    pub fn custom(input: impl Into<String>) -> Self {
+        Self {
+            input: input.into(),
+            is_custom: true,
+        }
+    }
+}
+
 impl RunCodeRequest {
+    pub fn set_test_cases(&mut self, cases: &[TestCase]) {
+        let stdin = cases
+            .iter()
+            .map(|c| c.input.trim())
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.test_cases_stdin = Some(stdin);
+    }
+
     pub fn new(
         lang: Language,
         test_cases: Option<String>,
@@ -3,9 +3,10 @@ use crate::errors::AppResult;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::from_value;
 use strum::Display;
+pub mod case_result;
 pub mod display;
 
-#[derive(Debug, Deserialize, Serialize, Display)]
+#[derive(Debug, Deserialize, Serialize, Display, Clone, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum State {
     Pending,
@@ -84,6 +85,14 @@ pub enum IntermediateParsed {
 pub struct RunSubmitResult(pub serde_json::Value);
 
 impl RunSubmitResult {
+    /// Judge state while the result is not available yet, `None` once it is.
+    pub fn get_state(&self) -> AppResult<Option<State>> {
+        Ok(match from_value(self.0.clone())? {
+            IntermediateParsed::Pending { state } => Some(state),
+            IntermediateParsed::Response { .. } => None,
+        })
+    }
+
     pub fn to_parsed_response(&self) -> AppResult<ParsedResponse> {
         let value = self.0.clone();
         let value_copy = value.clone();
@@ -245,6 +254,9 @@ impl std::fmt::Display for Memory {
 #[derive(Deserialize, Debug)]
 pub struct RunAccepted {
     pub status_runtime: String,
+    /// One `1`/`0` per testcase
+    #[serde(default)]
+    pub compare_result: String,
     pub memory: Memory,
     pub elapsed_time: u32,
     pub code_answer: Vec<String>,
@@ -262,6 +274,9 @@ pub struct RunAccepted {
 #[derive(Deserialize, Debug)]
 pub struct RunWrongAnswer {
     pub status_runtime: String,
+    /// One `1`/`0` per testcase
+    #[serde(default)]
+    pub compare_result: String,
     pub memory: Memory,
     pub elapsed_time: u32,
     pub code_answer: Vec<String>,
@@ -305,4 +320,6 @@ pub struct SubmitWrongAnswer {
     pub status_msg: String,
     pub last_testcase: String,
     pub expected_output: String,
+    #[serde(default)]
+    pub code_output: String,
 }
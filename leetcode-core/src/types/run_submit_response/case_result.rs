@@ -0,0 +1,221 @@
+use super::*;
+use crate::types::run::TestCase;
+
+/// Outcome of a single testcase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaseResult {
+    pub input: Option<String>,
+    /// Missing when the judge could not compute it, e.g. its own solution failed on the input
+    pub expected: Option<String>,
+    pub output: String,
+    pub stdout: String,
+    pub passed: bool,
+}
+
+impl CaseResult {
+    /// Both outputs split into tokens, the ones not shared by the other side are marked.
+    /// `None` when there is nothing to compare against or the outputs are too long to diff.
+    pub fn diff(&self) -> Option<(Vec<DiffToken<'_>>, Vec<DiffToken<'_>>)> {
+        self.expected
+            .as_deref()
+            .and_then(|expected| diff_tokens(expected, &self.output))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffToken<'a> {
+    Same(&'a str),
+    Changed(&'a str),
+}
+
+impl<'a> DiffToken<'a> {
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            DiffToken::Same(s) | DiffToken::Changed(s) => s,
+        }
+    }
+}
+
+/// Punctuation of the json-ish outputs is a token on its own, so that
+/// `[1,20]` and `[1,2]` differ only in `20`/`2`.
+fn tokenize(input: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (i, c) in input.char_indices() {
+        if c.is_alphanumeric() || matches!(c, '.' | '-' | '_') {
+            start.get_or_insert(i);
+            continue;
+        }
+        if let Some(s) = start.take() {
+            tokens.push(&input[s..i]);
+        }
+        tokens.push(&input[i..i + c.len_utf8()]);
+    }
+    if let Some(s) = start {
+        tokens.push(&input[s..]);
+    }
+    tokens
+}
+
+/// Outputs with more tokens than this are not diffed, the lcs table grows with
+/// the product of both lengths.
+pub const MAX_DIFF_TOKENS: usize = 500;
+
+/// Longest common subsequence over the tokens of both strings, `None` when
+/// either side has more than [`MAX_DIFF_TOKENS`] tokens.
+pub fn diff_tokens<'a>(
+    expected: &'a str,
+    actual: &'a str,
+) -> Option<(Vec<DiffToken<'a>>, Vec<DiffToken<'a>>)> {
+    let (left, right) = (tokenize(expected), tokenize(actual));
+    if left.len() > MAX_DIFF_TOKENS || right.len() > MAX_DIFF_TOKENS {
+        return None;
+    }
+    let mut lcs = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lcs[i][j] = if left[i] == right[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut left_diff, mut right_diff) = (vec![], vec![]);
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i] == right[j] {
+            left_diff.push(DiffToken::Same(left[i]));
+            right_diff.push(DiffToken::Same(right[j]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            left_diff.push(DiffToken::Changed(left[i]));
+            i += 1;
+        } else {
+            right_diff.push(DiffToken::Changed(right[j]));
+            j += 1;
+        }
+    }
+    left_diff.extend(left[i..].iter().map(|t| DiffToken::Changed(t)));
+    right_diff.extend(right[j..].iter().map(|t| DiffToken::Changed(t)));
+    Some((left_diff, right_diff))
+}
+
+fn run_case_results(
+    cases: &[TestCase],
+    code_answer: &[String],
+    expected_code_answer: &[String],
+    std_output_list: &[String],
+    compare_result: &str,
+) -> Vec<CaseResult> {
+    let compare_result: Vec<char> = compare_result.chars().collect();
+    (0..code_answer.len().max(cases.len()))
+        .map(|i| {
+            let output = code_answer.get(i).cloned().unwrap_or_default();
+            let expected = expected_code_answer.get(i).cloned();
+            let passed = match compare_result.get(i) {
+                Some(c) => *c == '1',
+                None => expected.as_ref() == Some(&output),
+            };
+            CaseResult {
+                input: cases.get(i).map(|c| c.input.clone()),
+                expected,
+                output,
+                stdout: std_output_list.get(i).cloned().unwrap_or_default(),
+                passed,
+            }
+        })
+        .collect()
+}
+
+impl ParsedResponse {
+    /// `cases` are the testcases of a run in the order they were sent. Submissions
+    /// only report the testcase they failed on.
+    pub fn get_case_results(&self, cases: &[TestCase]) -> Vec<CaseResult> {
+        match self {
+            ParsedResponse::RunAccepted(r) => run_case_results(
+                cases,
+                &r.code_answer,
+                &r.expected_code_answer,
+                &r.std_output_list,
+                &r.compare_result,
+            ),
+            ParsedResponse::RunWrongAnswer(r) => run_case_results(
+                cases,
+                &r.code_answer,
+                &r.expected_code_answer,
+                &r.std_output_list,
+                &r.compare_result,
+            ),
+            ParsedResponse::SubmitWrongAnswer(r) => vec![CaseResult {
+                input: Some(r.last_testcase.clone()),
+                expected: Some(r.expected_output.clone()),
+                output: r.code_output.clone(),
+                stdout: r.std_output.clone(),
+                passed: false,
+            }],
+            ParsedResponse::OutputLimitExceed(r) => vec![CaseResult {
+                input: Some(r.last_testcase.clone()),
+                expected: Some(r.expected_output.clone()),
+                output: r.code_output.clone(),
+                stdout: r.std_output.clone(),
+                passed: false,
+            }],
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_splits_punctuation() {
+        assert_eq!(
+            tokenize("[1,-20,\"ab\"]"),
+            ["[", "1", ",", "-20", ",", "\"", "ab", "\"", "]"]
+        );
+    }
+
+    #[test]
+    fn test_only_differing_tokens_are_marked() {
+        let (expected, actual) = diff_tokens("[0,1]", "[0,2,1]").unwrap();
+        assert_eq!(
+            expected
+                .iter()
+                .filter(|t| matches!(t, DiffToken::Changed(_)))
+                .count(),
+            0
+        );
+        assert_eq!(
+            actual,
+            [
+                DiffToken::Same("["),
+                DiffToken::Same("0"),
+                DiffToken::Same(","),
+                DiffToken::Changed("2"),
+                DiffToken::Changed(","),
+                DiffToken::Same("1"),
+                DiffToken::Same("]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_long_outputs_are_not_diffed() {
+        let long = vec!["1"; MAX_DIFF_TOKENS].join(",");
+        assert!(diff_tokens(&long, "[1]").is_none());
+        assert!(diff_tokens("[1]", &long).is_none());
+        let result = CaseResult {
+            input: None,
+            expected: Some(long.clone()),
+            output: "[1]".into(),
+            stdout: "".into(),
+            passed: false,
+        };
+        assert!(result.diff().is_none());
+    }
+}
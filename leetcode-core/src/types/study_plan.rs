@@ -0,0 +1,72 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanQuestion {
+    pub question_frontend_id: String,
+    pub title: String,
+    pub title_slug: String,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanSubGroup {
+    pub slug: String,
+    pub name: String,
+    pub questions: Vec<PlanQuestion>,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlan {
+    pub slug: String,
+    pub name: String,
+    pub plan_sub_groups: Vec<PlanSubGroup>,
+}
+
+impl StudyPlan {
+    /// Questions in the order of the plan, the sub groups flattened.
+    pub fn questions(&self) -> impl Iterator<Item = &PlanQuestion> {
+        self.plan_sub_groups.iter().flat_map(|g| g.questions.iter())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StudyPlanData {
+    #[serde(rename = "studyPlanV2Detail")]
+    pub study_plan: StudyPlan,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    pub data: StudyPlanData,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_study_plan() {
+        let json = r#"{"data": {"studyPlanV2Detail": {
+            "slug": "leetcode-75",
+            "name": "LeetCode 75",
+            "planSubGroups": [
+                {"slug": "array-string", "name": "Array / String", "questions": [
+                    {"questionFrontendId": "1768", "title": "Merge Strings Alternately", "titleSlug": "merge-strings-alternately"},
+                    {"questionFrontendId": "1071", "title": "Greatest Common Divisor of Strings", "titleSlug": "greatest-common-divisor-of-strings"}
+                ]},
+                {"slug": "two-pointers", "name": "Two Pointers", "questions": [
+                    {"questionFrontendId": "283", "title": "Move Zeroes", "titleSlug": "move-zeroes"}
+                ]}
+            ]
+        }}}"#;
+        let plan = serde_json::from_str::<Data>(json).unwrap().data.study_plan;
+        assert_eq!(plan.name, "LeetCode 75");
+        let ids = plan
+            .questions()
+            .map(|q| q.question_frontend_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["1768", "1071", "283"]);
+    }
+}
@@ -0,0 +1,97 @@
+use super::from_json_str;
+use super::run_submit_response::{status_from_id, StatusMessage};
+use serde::Deserialize;
+
+/// Share of accepted submissions per runtime/memory bucket.
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct Distribution {
+    pub lang: String,
+    pub distribution: Vec<(String, f64)>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionLang {
+    pub name: String,
+    pub verbose_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionQuestion {
+    pub question_id: String,
+    pub title_slug: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionDetails {
+    pub runtime: i64,
+    pub runtime_display: String,
+    pub runtime_percentile: Option<f64>,
+    #[serde(default, deserialize_with = "from_json_str")]
+    pub runtime_distribution: Option<Distribution>,
+    pub memory: i64,
+    pub memory_display: String,
+    pub memory_percentile: Option<f64>,
+    #[serde(default, deserialize_with = "from_json_str")]
+    pub memory_distribution: Option<Distribution>,
+    pub code: String,
+    pub timestamp: u64,
+    #[serde(deserialize_with = "status_from_id")]
+    pub status_code: StatusMessage,
+    pub lang: SubmissionLang,
+    pub question: SubmissionQuestion,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Data {
+    pub submission_details: SubmissionDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Root {
+    pub data: Data,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_submission_details() {
+        let json = r#"{
+            "data": {
+                "submissionDetails": {
+                    "runtime": 52,
+                    "runtimeDisplay": "52 ms",
+                    "runtimePercentile": 80.5,
+                    "runtimeDistribution": "{\"lang\": \"python3\", \"distribution\": [[\"40\", 2.5], [\"50\", 10.1]]}",
+                    "memory": 17200000,
+                    "memoryDisplay": "17.2 MB",
+                    "memoryPercentile": 60.1,
+                    "memoryDistribution": null,
+                    "code": "class Solution:\n    pass",
+                    "timestamp": 1698000000,
+                    "statusCode": 10,
+                    "lang": {"name": "python3", "verboseName": "Python3"},
+                    "question": {"questionId": "1", "titleSlug": "two-sum"}
+                }
+            }
+        }"#;
+        let root: Root = serde_json::from_str(json).unwrap();
+        let details = root.data.submission_details;
+        assert_eq!(details.runtime_display, "52 ms");
+        assert_eq!(
+            details.runtime_distribution,
+            Some(Distribution {
+                lang: "python3".into(),
+                distribution: vec![("40".into(), 2.5), ("50".into(), 10.1)],
+            })
+        );
+        assert_eq!(details.memory_distribution, None);
+        assert!(matches!(details.status_code, StatusMessage::Accepted));
+        assert_eq!(details.question.title_slug, "two-sum");
+    }
+}
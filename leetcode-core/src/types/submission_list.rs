@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Submission {
+    #[serde_as(as = "DisplayFromStr")]
+    pub id: u64,
+    pub status_display: String,
+    pub lang: String,
+    pub runtime: String,
+    pub memory: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionSubmissionList {
+    pub last_key: Option<String>,
+    pub has_next: bool,
+    pub submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Data {
+    pub question_submission_list: QuestionSubmissionList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Root {
+    pub data: Data,
+}
+
+impl Root {
+    pub fn get_submissions(self) -> Vec<Submission> {
+        self.data.question_submission_list.submissions
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.data.question_submission_list.has_next
+    }
+
+    pub fn get_last_key(&self) -> Option<&str> {
+        self.data.question_submission_list.last_key.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_submission_list() {
+        let json = r#"{
+            "data": {
+                "questionSubmissionList": {
+                    "lastKey": "1080234000",
+                    "hasNext": true,
+                    "submissions": [
+                        {
+                            "id": "1080234567",
+                            "statusDisplay": "Accepted",
+                            "lang": "python3",
+                            "runtime": "52 ms",
+                            "memory": "17.2 MB",
+                            "timestamp": "1698000000"
+                        },
+                        {
+                            "id": "1080234000",
+                            "statusDisplay": "Wrong Answer",
+                            "lang": "rust",
+                            "runtime": "N/A",
+                            "memory": "N/A",
+                            "timestamp": "1697990000"
+                        }
+                    ]
+                }
+            }
+        }"#;
+        let root: Root = serde_json::from_str(json).unwrap();
+        assert!(root.has_next());
+        assert_eq!(root.get_last_key(), Some("1080234000"));
+        let submissions = root.get_submissions();
+        assert_eq!(submissions.len(), 2);
+        assert_eq!(submissions[0].id, 1080234567);
+        assert_eq!(submissions[0].timestamp, 1698000000);
+        assert_eq!(submissions[1].status_display, "Wrong Answer");
+    }
+}
@@ -0,0 +1,79 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProgressStatus {
+    Solved,
+    Attempted,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ProgressStatus {
+    /// The `status` the question list reports for it.
+    pub fn question_status(&self) -> Option<String> {
+        match self {
+            ProgressStatus::Solved => Some("ac".into()),
+            ProgressStatus::Attempted => Some("notac".into()),
+            ProgressStatus::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressQuestion {
+    pub frontend_id: String,
+    pub title: String,
+    pub title_slug: String,
+    /// RFC 3339, e.g. `2024-05-01T09:30:00+00:00`
+    pub last_submitted_at: String,
+    pub question_status: ProgressStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProgressQuestionList {
+    pub total_num: i32,
+    pub questions: Vec<ProgressQuestion>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProgressData {
+    pub user_progress_question_list: UserProgressQuestionList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    pub data: UserProgressData,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_progress() {
+        let json = r#"{"data": {"userProgressQuestionList": {
+            "totalNum": 2,
+            "questions": [
+                {"frontendId": "1", "title": "Two Sum", "titleSlug": "two-sum",
+                 "lastSubmittedAt": "2024-05-01T09:30:00+00:00", "questionStatus": "SOLVED"},
+                {"frontendId": "42", "title": "Trapping Rain Water", "titleSlug": "trapping-rain-water",
+                 "lastSubmittedAt": "2024-04-30T18:00:00+00:00", "questionStatus": "ATTEMPTED"}
+            ]
+        }}}"#;
+        let list = serde_json::from_str::<Data>(json)
+            .unwrap()
+            .data
+            .user_progress_question_list;
+        assert_eq!(list.total_num, 2);
+        let statuses = list
+            .questions
+            .iter()
+            .map(|q| q.question_status.question_status())
+            .collect::<Vec<_>>();
+        assert_eq!(statuses, [Some("ac".into()), Some("notac".into())]);
+    }
+}
@@ -0,0 +1,59 @@
+use serde::Deserialize;
+
+/// Who the configured cookies belong to.
+#[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserStatus {
+    pub is_signed_in: bool,
+    /// Empty when signed out
+    #[serde(default)]
+    pub username: Option<String>,
+    /// `null` when signed out
+    #[serde(default)]
+    pub is_premium: Option<bool>,
+}
+
+impl UserStatus {
+    pub fn username(&self) -> &str {
+        self.username.as_deref().unwrap_or_default()
+    }
+
+    pub fn is_premium(&self) -> bool {
+        self.is_premium.unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryUserStatus {
+    pub user_status: UserStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Data {
+    pub data: QueryUserStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_in_user_status() {
+        let json = r#"{"data": {"userStatus": {"isSignedIn": true, "username": "alice", "isPremium": true}}}"#;
+        let status = serde_json::from_str::<Data>(json).unwrap().data.user_status;
+        assert!(status.is_signed_in);
+        assert_eq!(status.username(), "alice");
+        assert!(status.is_premium());
+    }
+
+    #[test]
+    fn test_signed_out_user_status() {
+        let json =
+            r#"{"data": {"userStatus": {"isSignedIn": false, "username": "", "isPremium": null}}}"#;
+        let status = serde_json::from_str::<Data>(json).unwrap().data.user_status;
+        assert!(!status.is_signed_in);
+        assert_eq!(status.username(), "");
+        assert!(!status.is_premium());
+    }
+}
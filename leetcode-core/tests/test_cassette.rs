@@ -0,0 +1,67 @@
+use leetcode_core::graphql::cassette::{self, Cassette, CassetteMode};
+use leetcode_core::graphql::query::{PollOptions, RunOrSubmitCodeCheckResult};
+use leetcode_core::types::language::Language;
+use leetcode_core::types::run_submit_response::ParsedResponse;
+use leetcode_core::{Host, LeetcodeClient, NetworkOptions, RunCodeRequest, Site};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_run_flow_is_replayed_offline() {
+    // nothing listens there, every response has to come from the cassette
+    let host = Host::new(Site::Com, Some("http://127.0.0.1:9"));
+    leetcode_core::init("csrf", "session", host, NetworkOptions::default())
+        .await
+        .unwrap();
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/test_cassette.json");
+    cassette::init(&fixture, CassetteMode::Replay).unwrap();
+
+    let request = RunCodeRequest::new(
+        Language::new("python3"),
+        Some("[2,7,11,15]\n9".into()),
+        "1".into(),
+        "class Solution: pass".into(),
+        "two-sum".into(),
+    );
+    let options = PollOptions {
+        interval: Duration::from_millis(1),
+        ..Default::default()
+    };
+    let states = std::sync::Mutex::new(vec![]);
+    let response = request
+        .poll_check_response(options, &|state| states.lock().unwrap().push(state.clone()))
+        .await
+        .unwrap();
+    assert!(matches!(response, ParsedResponse::RunAccepted(_)));
+    assert_eq!(states.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_run_flow_is_sent_with_the_given_client() {
+    let host = Host::new(Site::Com, Some("http://127.0.0.1:9"));
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/test_cassette.json");
+    let client = LeetcodeClient::new("csrf", "session", host, NetworkOptions::default())
+        .await
+        .unwrap()
+        .with_cassette(Arc::new(
+            Cassette::open(&fixture, CassetteMode::Replay).unwrap(),
+        ));
+
+    let request = RunCodeRequest::new(
+        Language::new("python3"),
+        Some("[2,7,11,15]\n9".into()),
+        "1".into(),
+        "class Solution: pass".into(),
+        "two-sum".into(),
+    );
+    let options = PollOptions {
+        interval: Duration::from_millis(1),
+        ..Default::default()
+    };
+    let response = request
+        .poll_check_response_with(&client, options, &|_| {})
+        .await
+        .unwrap();
+    assert!(matches!(response, ParsedResponse::RunAccepted(_)));
+}
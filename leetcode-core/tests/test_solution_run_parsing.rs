@@ -1,4 +1,4 @@
-use leetcode_core::types::run_submit_response::{ParsedResponse, RunSubmitResult};
+use leetcode_core::types::run_submit_response::{ParsedResponse, RunSubmitResult, State};
 use serde_json::{self, Value};
 
 const JSONS_STR: &str = include_str!("./test_solution_run_parsing.json");
@@ -47,10 +47,10 @@ fn test_run_correct_response_output() {
     let parsed_response = get_parsed_response("run_correct");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "Solution ran successfully for 2/2 cases.",
             "Memory used: 2.00 MB",
-            "Solution runtime: 0 ms",
+            "Solution runtime: 0 ms"
         ]
         .join("\n")
     )
@@ -61,10 +61,10 @@ fn test_run_wrong_response_output() {
     let parsed_response = get_parsed_response("run_wrong");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "Test Run Failed: 0/3 cases passed.",
             "Memory used: 16.39 MB",
-            "Solution runtime: 82 ms",
+            "Solution runtime: 82 ms"
         ]
         .join("\n")
     )
@@ -75,12 +75,12 @@ fn test_submit_correct_response_output() {
     let parsed_response = get_parsed_response("submit_correct");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "Solution ran successfully for 57/57 cases.",
             "Memory used: 2.35 MB",
             "Solution runtime: 2 ms",
             "Your runtime beats 83.9281 % of the submissions.",
-            "Your memory usage beats 39.7233 % of the submissions.",
+            "Your memory usage beats 39.7233 % of the submissions."
         ]
         .join("\n")
     )
@@ -91,10 +91,10 @@ fn test_submit_wrong_response_output() {
     let parsed_response = get_parsed_response("submit_wrong");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             "3/80 cases passed.",
             "Memory used: 2.32 MB",
-            "Solution runtime: N/A",
+            "Solution runtime: N/A"
         ]
         .join("\n")
     )
@@ -105,7 +105,7 @@ fn test_memory_limit_exceeded_response_output() {
     let parsed_response = get_parsed_response("memory_limit_exceeded");
     assert_eq!(
         parsed_response.to_string(),
-        vec!["Memory Limit Exceeded: 976.69 MB"].join("\n")
+        ["Memory Limit Exceeded: 976.69 MB"].join("\n")
     )
 }
 #[test]
@@ -113,12 +113,53 @@ fn test_output_limit_response_output() {
     let parsed_response = get_parsed_response("output_limit");
     assert_eq!(
         parsed_response.to_string(),
-        vec![
+        [
             r#"Output Limit Exceeded: Last Testcase: "maybe long testcase""#,
             r#"Expected Output:"true""#,
             r#"Std Output: "some_long_string""#,
-            r#"Code Output: """#,
+            r#"Code Output: """#
         ]
         .join("\n")
     )
 }
+
+#[test]
+fn test_should_report_judge_state_until_result_is_ready() {
+    let parsed: Value = serde_json::from_str(JSONS_STR).unwrap();
+    let get_state = |key_name: &str| {
+        let result: RunSubmitResult = serde_json::from_value(parsed[key_name].clone()).unwrap();
+        result.get_state().unwrap()
+    };
+    assert_eq!(get_state("pending"), Some(State::Pending));
+    assert_eq!(get_state("started"), Some(State::Started));
+    assert_eq!(get_state("run_correct"), None);
+}
+
+#[test]
+fn test_run_response_is_reported_per_case() {
+    use leetcode_core::graphql::query::run_code::TestCase;
+    let cases = [
+        TestCase::sample("[2,7,11,15]\n9"),
+        TestCase::sample("[3,2,4]\n6"),
+        TestCase::custom("[3,3]\n6"),
+    ];
+    let results = get_parsed_response("run_wrong").get_case_results(&cases);
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].input.as_deref(), Some("[2,7,11,15]\n9"));
+    assert_eq!(results[0].output, "[4,4]");
+    assert_eq!(results[0].expected.as_deref(), Some("[0,1]"));
+    assert!(results.iter().all(|r| !r.passed));
+
+    let results = get_parsed_response("run_correct").get_case_results(&cases[..2]);
+    assert!(results.iter().all(|r| r.passed));
+}
+
+#[test]
+fn test_submit_wrong_answer_reports_last_testcase() {
+    let results = get_parsed_response("submit_wrong").get_case_results(&[]);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].input.as_deref(), Some("\"sadbutsad\"\n\"sad\""));
+    assert_eq!(results[0].output, "4");
+    assert_eq!(results[0].expected.as_deref(), Some("0"));
+    assert!(!results[0].passed);
+}
@@ -1,10 +1,19 @@
 use super::theme::Theme;
-use crate::utils::{get_config_file_path, get_default_database_file_path, get_solutions_dir_path};
+use crate::utils::{
+    get_config_file_path, get_default_cache_dir_path, get_default_database_file_path,
+    get_solutions_dir_path,
+};
 use color_eyre::Result;
+use leetcode_core::graphql::cassette::CassetteMode;
+use leetcode_core::graphql::retry::RetryPolicy;
+use leetcode_core::types::language::LanguageOverride;
+use leetcode_core::{Host, NetworkOptions, Site};
 use leetcode_tui_shared::RoCell;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::prelude::*;
+use std::time::Duration;
 use std::{fs::create_dir_all, path::PathBuf};
 pub static CONFIG: RoCell<Config> = RoCell::new();
 
@@ -49,7 +58,7 @@ pub fn init() -> Result<()> {
                 .db
                 .path
                 .parent()
-                .map(|parent| create_dir_all(parent));
+                .map(create_dir_all);
         }
 
         parsed_config
@@ -57,54 +66,266 @@ pub fn init() -> Result<()> {
     Ok(())
 }
 
+/// Writes new cookies to config.toml, the rest of the file is kept as is.
+pub fn save_credentials(csrftoken: &str, lc_session: &str) -> Result<()> {
+    let config_file = get_config_file_path();
+    let contents = std::fs::read_to_string(&config_file)?;
+    std::fs::write(
+        &config_file,
+        replace_credentials(&contents, csrftoken, lc_session)?,
+    )?;
+    Ok(())
+}
+
+/// Only the two values are replaced, comments and key order stay untouched.
+fn replace_credentials(contents: &str, csrftoken: &str, lc_session: &str) -> Result<String> {
+    let mut document: toml_edit::DocumentMut = contents.parse()?;
+    for (key, value) in [("csrftoken", csrftoken), ("lc_session", lc_session)] {
+        let mut new_value = toml_edit::Value::from(value);
+        // keeps the spacing and a trailing comment of the old value
+        if let Some(old_value) = document.get(key).and_then(|item| item.as_value()) {
+            *new_value.decor_mut() = old_value.decor().clone();
+        }
+        document[key] = toml_edit::Item::Value(new_value);
+    }
+    Ok(document.to_string())
+}
+
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Config {
     pub csrftoken: String,
     pub lc_session: String,
+    /// "com" for leetcode.com or "cn" for leetcode.cn
+    #[serde(default)]
+    pub site: Site,
+    /// Overrides the url derived from `site`, e.g. to talk to a mock server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
     #[serde(default, skip_serializing)]
     pub db: Database,
+    #[serde(default, skip_serializing)]
+    pub cache: Cache,
+    #[serde(default, skip_serializing)]
+    pub retry: Retry,
+    #[serde(default, skip_serializing)]
+    pub network: Network,
+    #[serde(default, skip_serializing)]
+    pub cassette: Option<Cassette>,
+    #[serde(default, skip_serializing)]
+    pub lists: Lists,
+    /// Keyed by the leetcode slug, e.g. `[languages.rust]`
+    #[serde(default, skip_serializing)]
+    pub languages: HashMap<String, LanguageOverride>,
     #[serde(default = "get_solutions_dir_path", skip_serializing)]
     pub solutions_dir: PathBuf,
+    /// Runs the formatter of the language on a solution before it is sent.
+    #[serde(default, skip_serializing)]
+    pub format_solutions: bool,
     #[serde(default, skip_serializing)]
     pub theme: Theme,
 }
 
 impl Config {
+    pub fn host(&self) -> Host {
+        Host::new(self.site, self.base_url.as_deref())
+    }
+
     fn create_default_solution_dir() {
         create_dir_all(get_solutions_dir_path()).unwrap();
     }
 
     fn create_default_config(config_file: &PathBuf) {
-        let config_dir = config_file.as_path().parent().expect(&format!(
-            "Cannot get parent of the file path: {}",
-            config_file.display()
-        ));
-        create_dir_all(config_dir)
-            .expect(format!("Cannot create config directory @ {}", config_dir.display()).as_str());
+        let config_dir = config_file.as_path().parent().unwrap_or_else(|| {
+            panic!(
+                "Cannot get parent of the file path: {}",
+                config_file.display()
+            )
+        });
+        create_dir_all(config_dir).unwrap_or_else(|_| {
+            panic!("Cannot create config directory @ {}", config_dir.display())
+        });
         let default_config = Self::default();
         let default_config_str =
             toml::to_string(&default_config).expect("Cannot serialize default config to string.");
-        let mut file = File::create(&config_file)
-            .expect(format!("Cannot create file @ {}", config_file.display()).as_str());
-        file.write_all(default_config_str.as_bytes()).expect(
-            format!(
-                "Cannot write to the config file @ {}",
-                config_file.display()
-            )
-            .as_str(),
-        );
+        let mut file = File::create(config_file)
+            .unwrap_or_else(|_| panic!("Cannot create file @ {}", config_file.display()));
+        file.write_all(default_config_str.as_bytes())
+            .unwrap_or_else(|_| {
+                panic!(
+                    "Cannot write to the config file @ {}",
+                    config_file.display()
+                )
+            });
     }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Database {
     pub path: PathBuf,
+    /// Fetches the progress of the user and the new questions in the
+    /// background when the tui starts. Questions added below the highest
+    /// stored id still need a full sync.
+    #[serde(default = "Database::default_sync_on_startup")]
+    pub sync_on_startup: bool,
+}
+
+impl Database {
+    fn default_sync_on_startup() -> bool {
+        true
+    }
 }
 
 impl Default for Database {
     fn default() -> Self {
         Self {
             path: get_default_database_file_path(),
+            sync_on_startup: Self::default_sync_on_startup(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Cache {
+    #[serde(default = "get_default_cache_dir_path")]
+    pub path: PathBuf,
+    /// Upper bound of the on-disk response cache, 0 disables it.
+    #[serde(default = "Cache::default_max_size_mb")]
+    pub max_size_mb: u64,
+}
+
+impl Cache {
+    fn default_max_size_mb() -> u64 {
+        50
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self {
+            path: get_default_cache_dir_path(),
+            max_size_mb: Self::default_max_size_mb(),
+        }
+    }
+}
+
+/// Records the traffic with leetcode to `path`, or replays it from there without
+/// touching the network.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Cassette {
+    pub path: PathBuf,
+    pub mode: CassetteMode,
+}
+
+/// Curated lists synced along with the questions and shown among the topics.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Lists {
+    /// Study plan slugs, e.g. `leetcode-75`
+    pub study_plans: Vec<String>,
+    pub favorites: Vec<FavoriteList>,
+}
+
+impl Default for Lists {
+    fn default() -> Self {
+        Self {
+            study_plans: vec!["leetcode-75".into(), "top-interview-150".into()],
+            favorites: vec![],
+        }
+    }
+}
+
+/// A favorite list, the `listId` is the last part of its url.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FavoriteList {
+    pub id: String,
+    /// Shown instead of the id
+    pub name: Option<String>,
+}
+
+/// Retries of failed requests, with exponential backoff between the attempts.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Retry {
+    pub max_retries: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        let policy = RetryPolicy::default();
+        Self {
+            max_retries: policy.max_retries,
+            base_delay_ms: policy.base_delay.as_millis() as u64,
+            max_delay_ms: policy.max_delay.as_millis() as u64,
+        }
+    }
+}
+
+impl Retry {
+    pub fn policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_retries: self.max_retries,
+            base_delay: Duration::from_millis(self.base_delay_ms),
+            max_delay: Duration::from_millis(self.max_delay_ms),
         }
     }
 }
+
+/// Proxy, timeouts and user agent of the http client. Without a `proxy` the
+/// `HTTP_PROXY`/`HTTPS_PROXY`/`NO_PROXY` environment variables are used.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Network {
+    pub proxy: Option<String>,
+    pub use_env_proxy: bool,
+    pub connect_timeout_secs: Option<u64>,
+    pub timeout_secs: Option<u64>,
+    pub user_agent: Option<String>,
+    /// PEM file trusted on top of the system roots
+    pub ca_certificate: Option<PathBuf>,
+    pub accept_invalid_certs: bool,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        let options = NetworkOptions::default();
+        Self {
+            proxy: options.proxy,
+            use_env_proxy: options.use_env_proxy,
+            connect_timeout_secs: options.connect_timeout.map(|t| t.as_secs()),
+            timeout_secs: options.timeout.map(|t| t.as_secs()),
+            user_agent: options.user_agent,
+            ca_certificate: options.ca_certificate,
+            accept_invalid_certs: options.accept_invalid_certs,
+        }
+    }
+}
+
+impl Network {
+    pub fn options(&self) -> NetworkOptions {
+        NetworkOptions {
+            proxy: self.proxy.clone().filter(|p| !p.is_empty()),
+            use_env_proxy: self.use_env_proxy,
+            connect_timeout: self.connect_timeout_secs.map(Duration::from_secs),
+            timeout: self.timeout_secs.map(Duration::from_secs),
+            user_agent: self.user_agent.clone(),
+            ca_certificate: self.ca_certificate.clone(),
+            accept_invalid_certs: self.accept_invalid_certs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_credentials_keeps_comments_and_order() {
+        let contents = "# leetcode.com cookies\nlc_session = \"old\" # expires monthly\ncsrftoken = \"old\"\n\n[db]\n# kept\nsync_on_startup = false\n";
+        assert_eq!(
+            replace_credentials(contents, "csrf", "session").unwrap(),
+            "# leetcode.com cookies\nlc_session = \"session\" # expires monthly\ncsrftoken = \"csrf\"\n\n[db]\n# kept\nsync_on_startup = false\n"
+        );
+    }
+}
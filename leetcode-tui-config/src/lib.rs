@@ -6,7 +6,7 @@ pub mod key;
 pub mod theme;
 pub mod utils;
 
-pub use crate::config::CONFIG;
+pub use crate::config::{save_credentials, CONFIG};
 use color_eyre::Result;
 pub use log;
 
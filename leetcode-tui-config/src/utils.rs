@@ -56,6 +56,34 @@ pub(crate) fn get_default_database_file_path() -> PathBuf {
     get_data_dir().join("questions.db")
 }
 
+/// Returns the default response cache directory path
+/// Examples:
+/// - Windows: C:\Users\<username>\AppData\Local\leetcode-tui\cache
+/// - macOS: /Users/<username>/Library/Application Support/leetcode-tui/cache
+/// - Linux: /home/<username>/.local/share/leetcode-tui/cache
+pub(crate) fn get_default_cache_dir_path() -> PathBuf {
+    get_data_dir().join("cache")
+}
+
+/// Returns the path the languages fetched from leetcode are saved to
+/// Examples:
+/// - Windows: C:\Users\<username>\AppData\Local\leetcode-tui\languages.json
+/// - macOS: /Users/<username>/Library/Application Support/leetcode-tui/languages.json
+/// - Linux: /home/<username>/.local/share/leetcode-tui/languages.json
+pub fn get_languages_file_path() -> PathBuf {
+    get_data_dir().join("languages.json")
+}
+
+/// Returns the directory exported question lists default to, kept apart from
+/// the solutions so they are not scanned as solution files
+/// Examples:
+/// - Windows: C:\Users\<username>\AppData\Local\leetcode-tui\lists
+/// - macOS: /Users/<username>/Library/Application Support/leetcode-tui/lists
+/// - Linux: /home/<username>/.local/share/leetcode-tui/lists
+pub fn get_lists_dir_path() -> PathBuf {
+    get_data_dir().join("lists")
+}
+
 pub fn version() -> String {
     let author = clap::crate_authors!();
     let commit_hash = GIT_COMMIT_HASH.get().unwrap().clone();
@@ -1,4 +1,5 @@
 pub mod question;
+use leetcode_tui_db::QuestionIndex;
 use question::Questions;
 use topic::Topic;
 
@@ -19,9 +20,9 @@ impl MainContent {
 }
 
 impl MainContent {
-    pub async fn new() -> Self {
+    pub async fn new(index: &QuestionIndex) -> Self {
         Self {
-            topic: Topic::new().await,
+            topic: Topic::new(index).await,
             questions: Default::default(),
             visible: true,
         }
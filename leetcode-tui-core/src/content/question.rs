@@ -1,24 +1,36 @@
+mod calendar;
+mod case_results;
 pub(super) mod sol_dir;
 mod stats;
+mod testcases;
 use crate::utils::string_ops::replace_script_tags;
 use crate::SendError;
 use crate::{emit, utils::Paginate};
+use case_results::case_result_lines;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 use html2md::parse_html;
-use leetcode_core::graphql::query::{daily_coding_challenge, RunOrSubmitCodeCheckResult};
+use leetcode_core::graphql::query::run_code::TestCase;
+use leetcode_core::graphql::query::{
+    console_panel_config, daily_coding_challenge, favorite, question_votes, submission_list,
+    CancellationToken, PollOptions, RunOrSubmitCodeCheckResult,
+};
 use leetcode_core::types::run_submit_response::display::CustomDisplay;
-use leetcode_core::types::run_submit_response::ParsedResponse;
+use leetcode_core::types::run_submit_response::{ParsedResponse, State};
 use leetcode_core::{
-    GQLLeetcodeRequest, QuestionContentRequest, RunCodeRequest, SubmitCodeRequest,
+    GQLLeetcodeRequest, QuestionDetailRequest, QuestionVotesRequest, RunCodeRequest,
+    SubmissionDetailsRequest, SubmitCodeRequest,
 };
 use leetcode_tui_config::log;
-use leetcode_tui_db::{DbQuestion, DbTopic};
+use leetcode_tui_db::models::submission::DbSubmissionDetails;
+use leetcode_tui_db::{DbQuestion, DbQuestionRating, DbSubmission, DbTopic, Favorites};
 use leetcode_tui_shared::layout::Window;
 pub(crate) use sol_dir::init;
 use sol_dir::SOLUTION_FILE_MANAGER;
 use stats::Stats;
+use std::collections::HashMap;
 use std::rc::Rc;
+use testcases::TestcaseFile;
 
 pub struct Questions {
     paginate: Paginate<Rc<DbQuestion>>,
@@ -26,6 +38,11 @@ pub struct Questions {
     needle: Option<String>,
     matcher: SkimMatcherV2,
     show_stats: bool,
+    judge_cancel: Option<CancellationToken>,
+    /// Present while the questions are sorted by like ratio
+    like_ratios: Option<HashMap<u32, f64>>,
+    /// Missing ratings are fetched the first time the like ratio sort is turned on
+    ratings_requested: bool,
 }
 
 impl Default for Questions {
@@ -36,6 +53,9 @@ impl Default for Questions {
             ques_haystack: vec![],
             matcher: Default::default(),
             show_stats: Default::default(),
+            judge_cancel: None,
+            like_ratios: None,
+            ratings_requested: false,
         }
     }
 }
@@ -76,7 +96,7 @@ impl Questions {
                 )]
             ));
         };
-        return false;
+        false
     }
 
     fn widget_height(&self) -> usize {
@@ -86,20 +106,59 @@ impl Questions {
     }
 }
 
+/// Stores the votes of the questions without a rating, a batch of them per request.
+async fn fetch_missing_ratings() {
+    let Ok(slugs) = DbQuestionRating::unrated_slugs().emit_if_error() else {
+        return;
+    };
+    let title = "Fetching votes";
+    let total = slugs.len() as u32;
+    for (i, batch) in slugs.chunks(question_votes::BATCH_SIZE).enumerate() {
+        emit!(ProgressUpdate(
+            title.into(),
+            (i * question_votes::BATCH_SIZE) as u32,
+            total
+        ));
+        let Ok(response) = QuestionVotesRequest::new(batch)
+            .send()
+            .await
+            .emit_if_error()
+        else {
+            break;
+        };
+        let ratings = response
+            .into_votes()
+            .iter()
+            .filter_map(|votes| DbQuestionRating::try_from(votes).ok())
+            .collect();
+        if DbQuestionRating::save_all(&ratings)
+            .emit_if_error()
+            .is_err()
+        {
+            break;
+        }
+    }
+    emit!(ProgressUpdate(title.into(), 0, 0));
+    emit!(RatingsUpdate);
+}
+
 impl Questions {
     async fn get_question_content(slug: &str) -> Vec<String> {
-        let qc = QuestionContentRequest::new(slug.to_string());
-        if let Ok(content) = qc.send().await.emit_if_error() {
-            let lines = content
-                .data
-                .question
-                .html_to_text()
-                .lines()
+        let qd = QuestionDetailRequest::new(slug.to_string());
+        if let Ok(detail) = qd.send().await.emit_if_error() {
+            let question = detail.data.question;
+            // keeps the like ratio around for sorting
+            if let Ok(rating) = DbQuestionRating::try_from(&question).emit_if_error() {
+                rating.save_to_db().emit_if_error().ok();
+            }
+            let lines = question
+                .get_display_lines()
+                .iter()
                 .map(|l| replace_script_tags(l))
                 .collect::<Vec<String>>();
             return lines;
         }
-        return vec!["".into()];
+        vec!["".into()]
     }
 
     pub fn show_question_content(&self) -> bool {
@@ -116,15 +175,34 @@ impl Questions {
         true
     }
 
-    pub fn run_solution(&self) -> bool {
+    pub fn run_solution(&mut self) -> bool {
         self._run_solution(false)
     }
 
-    pub fn submit_solution(&self) -> bool {
+    pub fn submit_solution(&mut self) -> bool {
         self._run_solution(true)
     }
 
-    fn _run_solution(&self, is_submit: bool) -> bool {
+    /// Stops waiting for the result of the last run/submit.
+    pub fn cancel_judge(&mut self) -> bool {
+        if let Some(cancel) = self.judge_cancel.take() {
+            cancel.cancel();
+        }
+        false
+    }
+
+    fn show_judge_state(state: &State) {
+        let (title, progress) = match state {
+            State::Pending => ("Judge: queued", 1),
+            State::Started => ("Judge: judging", 2),
+            State::Success => ("Judge: done", 3),
+        };
+        emit!(ProgressUpdate(title.into(), progress, 3));
+    }
+
+    fn _run_solution(&mut self, is_submit: bool) -> bool {
+        let cancel = CancellationToken::new();
+        self.judge_cancel = Some(cancel.clone());
         if let Some(_hovered) = self.hovered() {
             let mut cloned_quest = _hovered.as_ref().clone();
             let id = _hovered.id.to_string();
@@ -149,8 +227,14 @@ impl Questions {
                             .get_solution_file(id.as_str(), selected_lang)
                             .cloned();
                         if let Ok(f) = selected_sol_file.emit_if_error() {
+                            f.format().await;
                             if let Ok(contents) = f.read_contents().await.emit_if_error() {
                                 let lang = f.language;
+                                let options = PollOptions {
+                                    cancel,
+                                    ..Default::default()
+                                };
+                                let mut cases = vec![];
                                 let request = if is_submit {
                                     SubmitCodeRequest::new(
                                         lang,
@@ -158,7 +242,7 @@ impl Questions {
                                         contents,
                                         f.title_slug,
                                     )
-                                    .poll_check_response()
+                                    .poll_check_response(options, &Self::show_judge_state)
                                     .await
                                 } else {
                                     let mut run_code_req = RunCodeRequest::new(
@@ -168,20 +252,34 @@ impl Questions {
                                         contents,
                                         f.title_slug,
                                     );
-                                    if let Err(e) = run_code_req
-                                        .set_sample_test_cases_if_none()
-                                        .await
-                                        .emit_if_error()
+                                    match run_code_req.get_sample_test_cases().await.emit_if_error()
                                     {
-                                        log::info!(
-                                            "error while setting the sample testcase list {}",
-                                            e
-                                        );
-                                        return;
-                                    } else {
-                                        run_code_req.poll_check_response().await
+                                        Ok(samples) => cases = samples,
+                                        Err(e) => {
+                                            log::info!(
+                                                "error while setting the sample testcase list {}",
+                                                e
+                                            );
+                                            return;
+                                        }
                                     }
+                                    let testcase_file = TestcaseFile::new(
+                                        &run_code_req.question_id,
+                                        &run_code_req.slug,
+                                    );
+                                    // every sample has one line per parameter
+                                    let params = cases.first().map(|c| c.input.lines().count());
+                                    match testcase_file.read_cases(params).await.emit_if_error() {
+                                        Ok(custom) => cases.extend(custom),
+                                        Err(_) => return,
+                                    }
+                                    run_code_req.set_test_cases(&cases);
+                                    run_code_req
+                                        .poll_check_response(options, &Self::show_judge_state)
+                                        .await
                                 };
+                                // hides the judge progress
+                                emit!(ProgressUpdate("Judge".into(), 0, 0));
 
                                 if let Ok(response) = request.emit_if_error() {
                                     if let Ok(update_result) =
@@ -189,8 +287,7 @@ impl Questions {
                                     {
                                         // when solution is just run against sample cases
                                         if update_result.is_some() {
-                                            // fetches latest result from db
-                                            emit!(QuestionUpdate);
+                                            emit!(QuestionUpdate(cloned_quest.clone()));
                                         }
                                     }
 
@@ -203,13 +300,16 @@ impl Questions {
                                             {
                                                 // when solution is accepted
                                                 if update_result.is_some() {
-                                                    // fetches latest result from db
-                                                    emit!(QuestionUpdate);
+                                                    emit!(QuestionUpdate(cloned_quest.clone()));
                                                 }
                                             };
                                         }
                                     }
-                                    emit!(Popup(response.get_display_lines()));
+                                    let mut lines = response.get_display_lines();
+                                    lines.extend(case_result_lines(
+                                        &response.get_case_results(&cases),
+                                    ));
+                                    emit!(Popup(lines));
                                 }
                             }
                         }
@@ -220,6 +320,132 @@ impl Questions {
         false
     }
 
+    pub fn show_submissions(&self) -> bool {
+        if let Some(_hovered) = self.hovered() {
+            let question_id = _hovered.id;
+            let slug = _hovered.title_slug.clone();
+            let title = _hovered.title.clone();
+            tokio::spawn(async move {
+                // refresh the local copy, falling back to it when offline
+                match submission_list::fetch_all(&slug).await {
+                    Ok(submissions) => {
+                        let submissions = submissions
+                            .into_iter()
+                            .map(|s| DbSubmission::new(question_id, s))
+                            .collect();
+                        DbSubmission::save_multiple_to_db(submissions)
+                            .emit_if_error()
+                            .ok();
+                    }
+                    Err(e) => log::info!("could not fetch submission list {}", e),
+                }
+
+                let Ok(mut submissions) =
+                    DbSubmission::fetch_by_question(question_id).emit_if_error()
+                else {
+                    return;
+                };
+                if submissions.is_empty() {
+                    emit!(Popup(title, vec!["No submissions yet".into()]));
+                    return;
+                }
+
+                let lines = submissions.iter().map(|s| s.to_string()).collect();
+                if let Some(selected) = emit!(SelectPopup("Submissions", lines)).await {
+                    let submission = &mut submissions[selected];
+                    if submission.details.is_none() {
+                        if let Ok(response) = SubmissionDetailsRequest::new(submission.id)
+                            .send()
+                            .await
+                            .emit_if_error()
+                        {
+                            let details: DbSubmissionDetails =
+                                response.data.submission_details.into();
+                            submission.set_details(details).emit_if_error().ok();
+                        }
+                    }
+                    emit!(Popup(title, Self::get_submission_display_lines(submission)));
+                }
+            });
+        }
+        false
+    }
+
+    fn get_submission_display_lines(submission: &DbSubmission) -> Vec<String> {
+        let mut lines = vec![
+            format!("Status: {}", submission.status),
+            format!("Language: {}", submission.lang),
+        ];
+        let Some(details) = submission.details.as_ref() else {
+            lines.push(format!("Runtime: {}", submission.runtime));
+            lines.push(format!("Memory: {}", submission.memory));
+            return lines;
+        };
+
+        let with_percentile = |value: &str, percentile: Option<f64>| match percentile {
+            Some(p) => format!("{value} (beats {p:.2}%)"),
+            None => value.to_string(),
+        };
+        lines.push(format!(
+            "Runtime: {}",
+            with_percentile(&submission.runtime, details.runtime_percentile)
+        ));
+        lines.push(format!(
+            "Memory: {}",
+            with_percentile(&submission.memory, details.memory_percentile)
+        ));
+
+        for (name, distribution) in [
+            ("Runtime", &details.runtime_distribution),
+            ("Memory", &details.memory_distribution),
+        ] {
+            if distribution.is_empty() {
+                continue;
+            }
+            lines.push("".into());
+            lines.push(format!("{name} distribution"));
+            for (bucket, share) in distribution {
+                let bar = "█".repeat(share.round() as usize);
+                lines.push(format!("{bucket: >8} {bar} {share:.2}%"));
+            }
+        }
+
+        lines.push("".into());
+        lines.push("```".into());
+        lines.extend(details.code.lines().map(|l| l.to_string()));
+        lines.push("```".into());
+        lines
+    }
+
+    /// Opens the custom testcase file of the question in the editor,
+    /// creating it from the examples on the first use.
+    pub fn edit_testcases(&self) -> bool {
+        if let Some(_hovered) = self.hovered() {
+            let id = _hovered.id.to_string();
+            let slug = _hovered.title_slug.clone();
+            tokio::spawn(async move {
+                let testcase_file = TestcaseFile::new(&id, &slug);
+                let samples = console_panel_config::Query::new(slug.clone())
+                    .send()
+                    .await
+                    .map(|r| r.data.question.example_testcase_list)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(TestCase::sample)
+                    .collect::<Vec<_>>();
+                if testcase_file
+                    .create_if_missing(&samples)
+                    .await
+                    .emit_if_error()
+                    .is_ok()
+                {
+                    emit!(Open(testcase_file.path().to_path_buf()));
+                }
+            });
+        }
+        false
+    }
+
     pub fn solve_for_language(&self) -> bool {
         if let Some(_hovered) = self.hovered() {
             let slug = _hovered.title_slug.clone();
@@ -241,7 +467,7 @@ impl Questions {
                     {
                         let selected_lang = editor_data.get_languages()[selected];
                         let editor_content = editor_data.get_editor_data_by_language(selected_lang);
-                        let question_content = editor_data.data.question.content.as_str();
+                        let question_content = editor_data.data.question.get_content();
 
                         if let Ok(file_name) =
                             editor_data.get_filename(selected_lang).emit_if_error()
@@ -278,8 +504,8 @@ impl Questions {
         false
     }
 
-    pub fn set_questions(&mut self, questions: Vec<DbQuestion>) {
-        self.ques_haystack = questions.into_iter().map(Rc::new).collect();
+    pub fn set_questions(&mut self, questions: Vec<Rc<DbQuestion>>) {
+        self.ques_haystack = questions;
         self.filter_questions();
     }
 
@@ -304,11 +530,58 @@ impl Questions {
                 .unwrap();
 
             db_question.save_to_db().unwrap();
+            // the index may not know the question yet
+            emit!(QuestionUpdate(db_question.clone()));
             emit!(Topic(DbTopic { slug: "all".into() }));
             emit!(AdhocQuestion(db_question));
         });
         false
     }
+
+    /// Adds the hovered question to the leetcode favorites or removes it.
+    pub fn toggle_favorite(&self) -> bool {
+        if let Some(_hovered) = self.hovered() {
+            let id = _hovered.id;
+            let slug = _hovered.title_slug.clone();
+            tokio::spawn(async move {
+                // the stored state, the one shown may be outdated by a sync
+                let Ok(is_favorite) = Favorites::is_favorite(id).emit_if_error() else {
+                    return;
+                };
+                let favorite = !is_favorite;
+                // the mutation wants the internal id of the question
+                let Ok(detail) = QuestionDetailRequest::new(slug)
+                    .send()
+                    .await
+                    .emit_if_error()
+                else {
+                    return;
+                };
+                if favorite::set_favorite(detail.data.question.question_id, favorite)
+                    .await
+                    .emit_if_error()
+                    .is_err()
+                {
+                    return;
+                }
+                if let Ok(question) = Favorites::set_favorite(id, favorite).emit_if_error() {
+                    emit!(QuestionUpdate(question));
+                }
+            });
+        }
+        false
+    }
+
+    /// Month grid of the daily challenges along with the current streak.
+    pub fn show_daily_calendar(&self) -> bool {
+        calendar::show_calendar();
+        false
+    }
+
+    pub fn catch_up_daily_question(&self) -> bool {
+        calendar::catch_up();
+        false
+    }
 }
 
 impl Questions {
@@ -334,8 +607,61 @@ impl Questions {
         }
     }
 
+    /// Toggles between id order and like ratio order. The first time the sort
+    /// is turned on the votes of the questions which were never rated are
+    /// fetched in the background, [`Questions::title`] tells how many are ranked
+    /// meanwhile and the list is sorted again once they arrive.
+    pub fn toggle_sort_by_likes(&mut self) -> bool {
+        self.like_ratios = match self.like_ratios {
+            Some(_) => None,
+            None => match DbQuestionRating::fetch_like_ratios().emit_if_error() {
+                Ok(ratios) => Some(ratios),
+                Err(_) => return false,
+            },
+        };
+        if self.like_ratios.is_some() && !self.ratings_requested {
+            self.ratings_requested = true;
+            tokio::spawn(fetch_missing_ratings());
+        }
+        self.filter_questions();
+        true
+    }
+
+    /// Picks up the ratings stored since the sort was turned on.
+    pub fn reload_like_ratios(&mut self) {
+        if self.like_ratios.is_none() {
+            return;
+        }
+        if let Ok(ratios) = DbQuestionRating::fetch_like_ratios().emit_if_error() {
+            self.like_ratios = Some(ratios);
+            self.filter_questions();
+        }
+    }
+
+    /// Title of the question pane, a like ratio sort names the share of the
+    /// questions it could rank, questions nobody voted on are not ranked.
+    pub fn title(&self) -> String {
+        let Some(ratios) = self.like_ratios.as_ref() else {
+            return "Questions".into();
+        };
+        let rated = self
+            .ques_haystack
+            .iter()
+            .filter(|q| ratios.contains_key(&q.id))
+            .count();
+        format!(
+            "Questions by likes, {rated}/{} rated",
+            self.ques_haystack.len()
+        )
+    }
+
     fn filter_questions(&mut self) {
         self.ques_haystack.sort();
+        if let Some(ratios) = self.like_ratios.as_ref() {
+            let ratio = |q: &DbQuestion| ratios.get(&q.id).copied().unwrap_or(-1.0);
+            self.ques_haystack
+                .sort_by(|a, b| ratio(b).total_cmp(&ratio(a)).then(a.id.cmp(&b.id)));
+        }
         let fil_quests = if let Some(needle) = self.needle.as_ref() {
             let quests: Vec<Rc<DbQuestion>> = self
                 .ques_haystack
@@ -353,7 +679,7 @@ impl Questions {
                     );
 
                     self.matcher
-                        .fuzzy_match(search_string.as_str(), &needle)
+                        .fuzzy_match(search_string.as_str(), needle)
                         .is_some()
                 })
                 .cloned()
@@ -0,0 +1,157 @@
+use crate::emit;
+use crate::SendError;
+use leetcode_core::{DailyChallengeCalendarRequest, GQLLeetcodeRequest};
+use leetcode_tui_config::log;
+use leetcode_tui_db::errors::DbErr;
+use leetcode_tui_db::{DbDailyChallenge, DbQuestion, DbTopic};
+use leetcode_tui_shared::date::{days_in_month, format_date, today, weekday};
+
+const WEEKDAYS: &str = "Mo Tu We Th Fr Sa Su";
+
+/// Refreshes the stored history of the month, keeps the stored copy when offline.
+pub(super) async fn refresh_month(year: i32, month: u32) {
+    match DailyChallengeCalendarRequest::new(year, month).send().await {
+        Ok(response) => {
+            let challenges = response
+                .data
+                .challenges
+                .iter()
+                .filter_map(|c| c.try_into().emit_if_error().ok())
+                .collect::<Vec<DbDailyChallenge>>();
+            DbDailyChallenge::save_multiple_to_db(&challenges)
+                .emit_if_error()
+                .ok();
+        }
+        Err(e) => log::info!("could not fetch daily challenge calendar {}", e),
+    }
+}
+
+pub(super) fn prev_month(year: i32, month: u32) -> (i32, u32) {
+    if month == 1 {
+        (year - 1, 12)
+    } else {
+        (year, month - 1)
+    }
+}
+
+/// Markdown lines of the month grid, the current streak and the missed days.
+pub(super) fn month_lines(year: i32, month: u32) -> Vec<String> {
+    let today = today();
+    let challenges = DbDailyChallenge::fetch_month(year, month)
+        .emit_if_error()
+        .unwrap_or_default();
+    let streak = DbDailyChallenge::current_streak(today)
+        .emit_if_error()
+        .unwrap_or_default();
+    let today = format_date(today.0, today.1, today.2);
+
+    let mut grid = vec![WEEKDAYS.to_string()];
+    let mut week = "   ".repeat(weekday(year, month, 1) as usize);
+    for day in 1..=days_in_month(year, month) {
+        let date = format_date(year, month, day);
+        let mark = match challenges.iter().find(|c| c.date == date) {
+            _ if date > today => '·',
+            Some(c) if c.completed => '✔',
+            Some(_) if date == today => '○',
+            Some(_) => '✗',
+            None => ' ',
+        };
+        week.push_str(&format!("{day:>2}{mark}"));
+        if weekday(year, month, day) == 6 {
+            grid.push(week.trim_end().to_string());
+            week.clear();
+        }
+    }
+    if !week.is_empty() {
+        grid.push(week.trim_end().to_string());
+    }
+
+    let mut lines = vec![format!("Current streak: {streak} day(s)"), "".into()];
+    lines.push("```".into());
+    lines.extend(grid);
+    lines.push("```".into());
+    lines.push("✔ solved  ✗ missed  ○ today  · upcoming".into());
+
+    let missed = missed(&challenges, &today);
+    if !missed.is_empty() {
+        lines.push("".into());
+        lines.push(format!("Missed this month ({})", missed.len()));
+        lines.extend(
+            missed
+                .iter()
+                .map(|c| format!("- {} {}. {}", c.date, c.question_id, c.title)),
+        );
+    }
+    lines
+}
+
+pub(super) fn missed<'a>(
+    challenges: &'a [DbDailyChallenge],
+    today: &str,
+) -> Vec<&'a DbDailyChallenge> {
+    challenges
+        .iter()
+        .filter(|c| !c.completed && c.date.as_str() < today)
+        .collect()
+}
+
+pub(super) fn show_calendar() {
+    tokio::spawn(async move {
+        let (year, month, _) = today();
+        refresh_month(year, month).await;
+        // the streak usually spans the month boundary
+        let (prev_year, prev_month) = prev_month(year, month);
+        refresh_month(prev_year, prev_month).await;
+        emit!(Popup(
+            format!("Daily challenges {year:04}-{month:02}"),
+            month_lines(year, month)
+        ));
+    });
+}
+
+/// Lets the user pick a missed day of this or the last month and opens its question.
+pub(super) fn catch_up() {
+    tokio::spawn(async move {
+        let (year, month, day) = today();
+        let (prev_year, prev_month) = prev_month(year, month);
+        refresh_month(year, month).await;
+        refresh_month(prev_year, prev_month).await;
+
+        let mut challenges = DbDailyChallenge::fetch_month(prev_year, prev_month)
+            .emit_if_error()
+            .unwrap_or_default();
+        challenges.extend(
+            DbDailyChallenge::fetch_month(year, month)
+                .emit_if_error()
+                .unwrap_or_default(),
+        );
+        let missed = missed(&challenges, &format_date(year, month, day));
+        if missed.is_empty() {
+            emit!(Popup(
+                "Daily challenges".to_string(),
+                vec!["No missed daily challenges".into()]
+            ));
+            return;
+        }
+
+        let lines = missed
+            .iter()
+            .rev()
+            .map(|c| format!("{} {}. {}", c.date, c.question_id, c.title))
+            .collect();
+        if let Some(selected) = emit!(SelectPopup("Missed daily challenges", lines)).await {
+            let challenge = missed[missed.len() - 1 - selected];
+            let question = DbQuestion::get_question_by_id(challenge.question_id)
+                .and_then(|q| {
+                    q.ok_or(DbErr::QuestionsNotFoundInDb(
+                        challenge.question_id.to_string(),
+                    ))
+                })
+                .emit_if_error();
+            if let Ok(question) = question {
+                emit!(Topic(DbTopic { slug: "all".into() }));
+                emit!(AdhocQuestion(question));
+            }
+        }
+    });
+}
@@ -0,0 +1,206 @@
+use leetcode_core::types::run_submit_response::case_result::{CaseResult, DiffToken};
+
+/// Characters that would otherwise be taken as markdown by the popup.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '<' | '>' | '|'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Changed tokens are wrapped in `marker`, consecutive ones share the same span.
+fn highlight(tokens: &[DiffToken], marker: &str) -> String {
+    let mut line = String::new();
+    let mut changed = String::new();
+    for token in tokens {
+        match token {
+            DiffToken::Changed(t) => changed.push_str(t),
+            DiffToken::Same(t) => {
+                if !changed.is_empty() {
+                    line.push_str(&format!("{marker}{}{marker}", escape_markdown(&changed)));
+                    changed.clear();
+                }
+                line.push_str(&escape_markdown(t));
+            }
+        }
+    }
+    if !changed.is_empty() {
+        line.push_str(&format!("{marker}{}{marker}", escape_markdown(&changed)));
+    }
+    line
+}
+
+/// A table cell, `width` is the displayed width of the unescaped text.
+struct Cell {
+    width: usize,
+    text: String,
+}
+
+impl Cell {
+    fn plain(text: &str) -> Self {
+        Self {
+            width: text.chars().count(),
+            text: escape_markdown(text),
+        }
+    }
+
+    fn highlighted(tokens: &[DiffToken], marker: &str) -> Self {
+        Self {
+            width: tokens.iter().map(|t| t.as_str().chars().count()).sum(),
+            text: highlight(tokens, marker),
+        }
+    }
+
+    fn header(text: &str) -> Self {
+        Self {
+            width: text.chars().count(),
+            text: format!("**{text}**"),
+        }
+    }
+}
+
+/// Multi line values are kept on one row of the table.
+fn one_line(text: &str, separator: &str) -> String {
+    text.trim()
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// One row per testcase with the input, the expected and the actual output and
+/// the stdout next to each other. Tokens only present in the expected output
+/// are in bold, the ones only present in the actual output are struck through.
+///
+/// The popup does not render markdown tables, the columns are padded instead.
+pub(crate) fn case_result_lines(results: &[CaseResult]) -> Vec<String> {
+    if results.is_empty() {
+        return vec![];
+    }
+    let with_stdout = results.iter().any(|r| !r.stdout.trim().is_empty());
+
+    let mut header = vec![
+        Cell::header("Case"),
+        Cell::header("Input"),
+        Cell::header("Expected"),
+        Cell::header("Output"),
+    ];
+    if with_stdout {
+        header.push(Cell::header("Stdout"));
+    }
+    let mut rows = vec![header];
+    for (i, result) in results.iter().enumerate() {
+        let verdict = if result.passed { "✓" } else { "✗" };
+        let input = one_line(result.input.as_deref().unwrap_or_default(), ", ");
+        let (expected, output) = match result.diff() {
+            Some((expected, output)) if !result.passed => (
+                Cell::highlighted(&expected, "**"),
+                Cell::highlighted(&output, "~~"),
+            ),
+            _ => (
+                Cell::plain(result.expected.as_deref().unwrap_or("-")),
+                Cell::plain(&result.output),
+            ),
+        };
+        let mut row = vec![
+            Cell::plain(&format!("{verdict} {}", i + 1)),
+            Cell::plain(&input),
+            expected,
+            output,
+        ];
+        if with_stdout {
+            row.push(Cell::plain(&one_line(&result.stdout, " ⏎ ")));
+        }
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|col| rows.iter().map(|row| row[col].width).max().unwrap_or(0))
+        .collect();
+    let render = |row: &[Cell]| {
+        let last = row.len() - 1;
+        row.iter()
+            .enumerate()
+            .map(|(col, cell)| {
+                // trailing spaces would end the line with a hard break
+                let padding = if col == last {
+                    0
+                } else {
+                    widths[col] - cell.width
+                };
+                format!("{}{}", cell.text, " ".repeat(padding))
+            })
+            .collect::<Vec<_>>()
+            .join(" │ ")
+    };
+
+    let mut lines = vec!["".into(), render(&rows[0])];
+    lines.push(
+        widths
+            .iter()
+            .map(|w| "─".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("─┼─"),
+    );
+    lines.extend(rows[1..].iter().map(|row| render(row)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(input: &str, expected: &str, output: &str, stdout: &str) -> CaseResult {
+        CaseResult {
+            input: Some(input.into()),
+            expected: Some(expected.into()),
+            output: output.into(),
+            stdout: stdout.into(),
+            passed: expected == output,
+        }
+    }
+
+    #[test]
+    fn test_one_row_per_case_with_aligned_columns() {
+        let lines = case_result_lines(&[
+            result("[1,2]\n3", "[0,1]", "[0,1]", ""),
+            result("[10]\n4", "[]", "[]", ""),
+        ]);
+        assert_eq!(
+            lines,
+            [
+                "",
+                "**Case** │ **Input**    │ **Expected** │ **Output**",
+                "─────┼──────────┼──────────┼───────",
+                "✓ 1  │ \\[1,2\\], 3 │ \\[0,1\\]    │ \\[0,1\\]",
+                "✓ 2  │ \\[10\\], 4  │ \\[\\]       │ \\[\\]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failed_case_highlights_the_difference() {
+        let lines = case_result_lines(&[result("1", "[1,2]", "[1,3]", "dbg\nmore\n")]);
+        assert_eq!(lines[1].matches("**").count(), 10);
+        assert!(lines[3].starts_with("✗ 1"));
+        assert!(lines[3].contains("\\[1,**2**\\]"));
+        assert!(lines[3].contains("\\[1,~~3~~\\]"));
+        assert!(lines[3].ends_with("dbg ⏎ more"));
+    }
+
+    #[test]
+    fn test_missing_expected_output_and_no_results() {
+        let mut missing = result("1", "", "2", "");
+        missing.expected = None;
+        let lines = case_result_lines(&[missing]);
+        assert!(lines[3].contains("│ -"));
+        assert!(case_result_lines(&[]).is_empty());
+    }
+}
@@ -1,12 +1,18 @@
 use indexmap::IndexSet;
-use std::{collections::HashMap, hash::Hash, path::PathBuf, sync::RwLock};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
 use tokio::fs::read_to_string;
 
-use leetcode_tui_config::CONFIG;
 use leetcode_core::types::language::Language;
+use leetcode_tui_config::{log, CONFIG};
 use regex::Regex;
 use std::sync::OnceLock;
 
+use super::testcases::TESTCASE_FILE_EXTENSION;
 use crate::errors::{CoreError, CoreResult};
 pub static FILENAME_REGEX: OnceLock<regex::Regex> = OnceLock::new();
 pub static SOLUTION_FILE_MANAGER: OnceLock<RwLock<SolutionFileManager>> = OnceLock::new();
@@ -35,6 +41,48 @@ impl SolutionFile {
     pub async fn read_contents(&self) -> CoreResult<String> {
         Ok(read_to_string(&self.path).await?)
     }
+
+    /// Runs the formatter of the language on the file when `format_solutions` is
+    /// set and the language has one. A failing formatter leaves the file as it was.
+    pub async fn format(&self) {
+        if !CONFIG.as_ref().format_solutions {
+            return;
+        }
+        let Some(formatter) = self.language.formatter() else {
+            return;
+        };
+        let Some(mut command) = formatter_command(&formatter, &self.path) else {
+            return;
+        };
+        match command.output().await {
+            Ok(output) if output.status.success() => (),
+            Ok(output) => log::info!(
+                "{formatter} failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ),
+            Err(e) => log::info!("could not run {formatter}: {e}"),
+        }
+    }
+}
+
+/// The formatter is spawned without a shell, the path is passed as its own
+/// argument in place of `{file}`, or last when the command does not mention it.
+fn formatter_command(formatter: &str, path: &Path) -> Option<tokio::process::Command> {
+    let mut words = formatter.split_whitespace();
+    let mut command = tokio::process::Command::new(words.next()?);
+    let mut has_file = false;
+    for word in words {
+        if word == "{file}" {
+            command.arg(path);
+            has_file = true;
+        } else {
+            command.arg(word);
+        }
+    }
+    if !has_file {
+        command.arg(path);
+    }
+    Some(command)
 }
 
 #[derive(Debug, Default)]
@@ -91,12 +139,17 @@ impl TryFrom<PathBuf> for SolutionFileManager {
         for maybe_entry in std::fs::read_dir(value)? {
             let entry = maybe_entry?;
             let file_path = entry.path();
-            let maybe_sol_file: CoreResult<SolutionFile> = file_path.try_into();
-            if let Err(CoreError::FileNameDoesNotExistError(_)) = maybe_sol_file {
+            // custom testcases live next to the solutions
+            if file_path.extension().and_then(|e| e.to_str()) == Some(TESTCASE_FILE_EXTENSION) {
                 continue;
-            } else {
-                let sol_file = maybe_sol_file?;
-                sfm.add_solution_file(sol_file);
+            }
+            match SolutionFile::try_from(file_path) {
+                Ok(sol_file) => sfm.add_solution_file(sol_file),
+                Err(CoreError::FileNameDoesNotExistError(_)) => continue,
+                Err(CoreError::FileNameFormatDoesNotMatch(path)) => {
+                    log::info!("skipping {}, not a solution file", path.display());
+                }
+                Err(e) => return Err(e),
             }
         }
         Ok(sfm)
@@ -136,7 +189,7 @@ impl TryFrom<PathBuf> for SolutionFile {
         }
 
         if let (Some(qid), Some(slug), Some(lang_id)) = (res[0], res[1], res[2]) {
-            let lang: Language = lang_id.parse::<u32>()?.into();
+            let lang = Language::from_id(lang_id.parse::<u32>()?);
             Ok(Self {
                 question_id: qid.to_string(),
                 title_slug: slug.to_string(),
@@ -148,3 +201,43 @@ impl TryFrom<PathBuf> for SolutionFile {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsStr;
+
+    fn args(formatter: &str, path: &str) -> Option<Vec<String>> {
+        formatter_command(formatter, Path::new(path)).map(|command| {
+            let command = command.as_std();
+            std::iter::once(command.get_program())
+                .chain(command.get_args())
+                .map(OsStr::to_string_lossy)
+                .map(String::from)
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_path_is_a_separate_argument() {
+        assert_eq!(
+            args("rustfmt --edition 2021 {file}", "/tmp/a \"b\"; rm -rf ~.rs").unwrap(),
+            ["rustfmt", "--edition", "2021", "/tmp/a \"b\"; rm -rf ~.rs"]
+        );
+        assert_eq!(args("ktfmt", "a.kt").unwrap(), ["ktfmt", "a.kt"]);
+        assert!(args("  ", "a.kt").is_none());
+    }
+
+    #[test]
+    fn test_scan_skips_files_which_are_not_solutions() {
+        let dir = std::env::temp_dir().join(format!("leetcode-tui-sol-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("0001_two-sum_2.py"), "").unwrap();
+        std::fs::write(dir.join("custom-blind-75.toml"), "questions = []\n").unwrap();
+
+        let sfm = SolutionFileManager::try_from(dir.clone()).unwrap();
+        assert_eq!(sfm.id_language_map.len(), 1);
+        assert_eq!(sfm.id_language_map["1"].len(), 1);
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
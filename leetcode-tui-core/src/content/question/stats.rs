@@ -20,6 +20,7 @@ pub enum QuestionStatus {
     EasyAccepted,
     MediumAccepted,
     HardAccepted,
+    WithSolution,
 }
 
 use QuestionStatus::*;
@@ -32,19 +33,21 @@ impl Display for QuestionStatus {
             EasyAccepted => "Easy Accepted",
             MediumAccepted => "Medium Accepted",
             HardAccepted => "Hard Accepted",
+            WithSolution => "With Solution",
         };
         write!(f, "{}", string)
     }
 }
 
-impl Into<Style> for QuestionStatus {
-    fn into(self) -> Style {
-        match self {
+impl From<QuestionStatus> for Style {
+    fn from(val: QuestionStatus) -> Self {
+        match val {
             Accepted => CONFIG.as_ref().theme.question.normal.easy,
             Attempted => CONFIG.as_ref().theme.question.normal.easy,
             EasyAccepted => CONFIG.as_ref().theme.question.normal.easy,
             MediumAccepted => CONFIG.as_ref().theme.question.normal.medium,
             HardAccepted => CONFIG.as_ref().theme.question.normal.hard,
+            WithSolution => CONFIG.as_ref().theme.question.normal.easy,
         }
         .into()
     }
@@ -60,6 +63,11 @@ impl<'a> Stats<'a> {
                 self.get_total_question() - self.get_not_attempted(),
                 self.get_total_question(),
             ),
+            (
+                WithSolution,
+                self.get_with_solution(),
+                self.get_total_question(),
+            ),
             (
                 EasyAccepted,
                 self.get_easy_accepted(),
@@ -120,6 +128,20 @@ impl<'a> Stats<'a> {
         self.get_diff_accepted("ac", "Hard")
     }
 
+    /// Questions with an official solution
+    pub fn get_with_solution(&self) -> usize {
+        self.qm.iter().filter(|q| q.has_solution).count()
+    }
+
+    /// Mean acceptance rate in percent of the questions that have one.
+    pub fn get_average_ac_rate(&self) -> Option<f64> {
+        let rates = self.qm.iter().filter_map(|q| q.ac_rate).collect::<Vec<_>>();
+        if rates.is_empty() {
+            return None;
+        }
+        Some(rates.iter().sum::<f64>() / rates.len() as f64)
+    }
+
     pub fn get_diff_accepted(&self, status: &str, difficulty: &str) -> usize {
         self.qm
             .iter()
@@ -0,0 +1,132 @@
+use crate::errors::{CoreError, CoreResult};
+use leetcode_core::graphql::query::run_code::TestCase;
+use leetcode_tui_config::CONFIG;
+use std::path::{Path, PathBuf};
+
+pub(crate) const TESTCASE_FILE_EXTENSION: &str = "testcases";
+
+/// Custom testcases of a question, kept next to its solution files,
+/// e.g. `0001_two-sum.testcases`.
+///
+/// One testcase per block, blocks are separated by an empty line and
+/// lines starting with `#` are ignored. Each line of a block is one parameter.
+pub(crate) struct TestcaseFile {
+    path: PathBuf,
+}
+
+impl TestcaseFile {
+    pub(crate) fn new(question_id: &str, title_slug: &str) -> Self {
+        let file_name = format!("{question_id:0>4}_{title_slug}.{TESTCASE_FILE_EXTENSION}");
+        Self {
+            path: CONFIG.as_ref().solutions_dir.join(file_name),
+        }
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    /// Missing file means no custom testcases. `params` is the number of lines
+    /// every testcase must have, when known.
+    pub(crate) async fn read_cases(&self, params: Option<usize>) -> CoreResult<Vec<TestCase>> {
+        if !self.path.exists() {
+            return Ok(vec![]);
+        }
+        let contents = tokio::fs::read_to_string(&self.path).await?;
+        parse_cases(&contents, params)
+    }
+
+    /// Writes a commented template showing the format of the given samples.
+    pub(crate) async fn create_if_missing(&self, samples: &[TestCase]) -> CoreResult<()> {
+        if self.path.exists() {
+            return Ok(());
+        }
+        let mut contents = vec![
+            "# Custom testcases, sent along with the examples when the solution is run.".into(),
+            "# One testcase per block, separate the blocks with an empty line.".into(),
+            "# Lines starting with # are ignored. The examples look like:".into(),
+        ];
+        for sample in samples {
+            contents.push("#".into());
+            contents.extend(sample.input.lines().map(|l| format!("# {l}")));
+        }
+        contents.push("".into());
+        tokio::fs::write(&self.path, contents.join("\n")).await?;
+        Ok(())
+    }
+}
+
+fn parse_cases(contents: &str, params: Option<usize>) -> CoreResult<Vec<TestCase>> {
+    let mut blocks = vec![];
+    let mut current: Vec<&str> = vec![];
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        current.push(line);
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    let mut cases = vec![];
+    for (i, block) in blocks.into_iter().enumerate() {
+        if let Some(params) = params.filter(|params| *params != block.len()) {
+            return Err(CoreError::TestcaseParamCountError {
+                case: i + 1,
+                lines: block.len(),
+                params,
+            });
+        }
+        cases.push(TestCase::custom(block.join("\n")));
+    }
+    Ok(cases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(cases: &[TestCase]) -> Vec<&str> {
+        cases.iter().map(|c| c.input.as_str()).collect()
+    }
+
+    #[test]
+    fn test_blocks_are_separated_by_blank_lines() {
+        let contents = "# comment\n[1,2]\n3\n\n\n   \n[4]\n5\n# trailing comment\n";
+        let cases = parse_cases(contents, Some(2)).unwrap();
+        assert_eq!(inputs(&cases), ["[1,2]\n3", "[4]\n5"]);
+        assert!(cases.iter().all(|c| c.is_custom));
+    }
+
+    #[test]
+    fn test_trailing_whitespace_is_trimmed() {
+        let cases = parse_cases("  [1,2]  \t\n3 \r\n", None).unwrap();
+        assert_eq!(inputs(&cases), ["[1,2]\n3"]);
+        assert!(parse_cases("# only comments\n\n", Some(2))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_wrong_parameter_count_is_rejected() {
+        let err = parse_cases("[1,2]\n3\n\n[4]\n", Some(2)).unwrap_err();
+        assert!(matches!(
+            err,
+            CoreError::TestcaseParamCountError {
+                case: 2,
+                lines: 1,
+                params: 2
+            }
+        ));
+        // nothing to check against
+        assert_eq!(parse_cases("[4]\n", None).unwrap().len(), 1);
+    }
+}
@@ -1,25 +1,92 @@
 use crate::emit;
+use crate::session::read_input;
 use crate::utils::Paginate;
-use leetcode_tui_db::DbTopic;
+use crate::SendError;
+use leetcode_tui_config::utils::get_lists_dir_path;
+use leetcode_tui_db::{DbList, DbTopic, Favorites, QuestionIndex};
 use leetcode_tui_shared::layout::Window;
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 pub struct Topic {
     paginate: Paginate<DbTopic>,
     topics: Vec<DbTopic>,
+    /// Question ids of the lists, their progress is taken from the index
+    lists: Vec<(DbList, Vec<u32>)>,
+    /// Name and progress of the lists and favorites, keyed by their topic slug
+    labels: HashMap<String, String>,
 }
 
-impl<'a> Topic {
-    pub(crate) async fn new() -> Self {
-        let mut topics = vec![DbTopic::new("all")];
-        topics.extend(DbTopic::fetch_all().unwrap());
-        let s = Self {
+impl Topic {
+    pub(crate) async fn new(index: &QuestionIndex) -> Self {
+        let (topics, lists) = Self::load_topics();
+        let mut s = Self {
             paginate: Paginate::new(topics.clone()),
             topics,
+            lists,
+            labels: HashMap::new(),
         };
+        s.refresh_progress(index);
         s.notify_change();
         s
     }
 
+    /// "all" and the favorites, then the curated lists followed by the tags.
+    /// The lists come along with their question ids.
+    fn load_topics() -> (Vec<DbTopic>, Vec<(DbList, Vec<u32>)>) {
+        let lists: Vec<_> = DbList::fetch_all()
+            .emit_if_error()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|list| {
+                let ids = list.question_ids().emit_if_error().ok()?;
+                Some((list, ids))
+            })
+            .collect();
+        let mut topics = vec![DbTopic::new("all"), Favorites::topic()];
+        topics.extend(lists.iter().map(|(list, _)| list.as_topic()));
+        topics.extend(DbTopic::fetch_all().emit_if_error().unwrap_or_default());
+        (topics, lists)
+    }
+
+    /// Reloads the topics after a sync, the hovered one stays selected.
+    pub fn refresh(&mut self, index: &QuestionIndex) {
+        let hovered = self.hovered().cloned();
+        (self.topics, self.lists) = Self::load_topics();
+        self.paginate.update_list(self.topics.clone());
+        if let Some(topic) = hovered {
+            self.set_topic(&topic);
+        }
+        self.refresh_progress(index);
+    }
+
+    /// Taken from the index, nothing is read from the db.
+    pub fn refresh_progress(&mut self, index: &QuestionIndex) {
+        self.labels = self
+            .lists
+            .iter()
+            .map(|(list, ids)| {
+                let (accepted, total) = index.progress(ids);
+                (
+                    list.as_topic().slug,
+                    format!("{} ({accepted}/{total})", list.name),
+                )
+            })
+            .collect();
+        self.labels.insert(
+            Favorites::topic().slug,
+            format!("Favorites ({})", index.favorites_count()),
+        );
+    }
+
+    /// Shown in the topic pane, lists come with their progress.
+    pub fn label<'a>(&'a self, topic: &'a DbTopic) -> &'a str {
+        self.labels
+            .get(&topic.slug)
+            .map(String::as_str)
+            .unwrap_or(topic.slug.as_str())
+    }
+
     pub fn next_topic(&mut self) -> bool {
         let has_topic_changed = self.paginate.next_elem(self.widget_height());
         if has_topic_changed {
@@ -46,7 +113,7 @@ impl<'a> Topic {
         if let Some(id) = self.topics.iter().position(|x| x.slug == topic.slug) {
             self.paginate.set_element_by_index(id, self.widget_height());
         }
-        return true;
+        true
     }
 
     pub fn window(&self) -> &[DbTopic] {
@@ -65,3 +132,63 @@ impl Topic {
         self.paginate.hovered()
     }
 }
+
+impl Topic {
+    /// Saves the questions of a CSV/TOML file as a list, the unknown entries
+    /// are reported back.
+    pub fn import_list(&self) -> bool {
+        tokio::spawn(async move {
+            let Some(path) = read_input("Import list (.csv/.toml): ", None).await else {
+                return;
+            };
+            let Ok(report) = DbList::import(&PathBuf::from(path), None).emit_if_error() else {
+                return;
+            };
+            emit!(TopicsUpdate);
+            emit!(Topic(report.list.as_topic()));
+
+            let mut lines = vec![format!("Imported {} question(s)", report.imported)];
+            if !report.unknown.is_empty() {
+                lines.push("".into());
+                lines.push(format!("Unknown entries ({})", report.unknown.len()));
+                lines.extend(report.unknown.iter().map(|entry| format!("- {entry}")));
+            }
+            emit!(Popup(report.list.name, lines));
+        });
+        false
+    }
+
+    /// Writes the hovered list to a file, the extension picks the format.
+    pub fn export_list(&self) -> bool {
+        let Some(list_id) = self.hovered().and_then(|t| t.list_id()) else {
+            emit!(Popup(
+                "Export list".to_string(),
+                vec!["Select a list in the topic pane first".into()]
+            ));
+            return false;
+        };
+        let list_id = list_id.to_string();
+        tokio::spawn(async move {
+            let Ok(list) = DbList::get_list_by_id(&list_id).emit_if_error() else {
+                return;
+            };
+            let default = get_lists_dir_path()
+                .join(format!("{}.toml", list.id))
+                .display()
+                .to_string();
+            let Some(path) = read_input("Export list (.csv/.toml): ", Some(default)).await else {
+                return;
+            };
+            let path_buf = PathBuf::from(&path);
+            if let Some(parent) = path_buf.parent() {
+                if std::fs::create_dir_all(parent).emit_if_error().is_err() {
+                    return;
+                }
+            }
+            if list.export(&path_buf).emit_if_error().is_ok() {
+                emit!(Popup(list.name, vec![format!("Exported to {path}")]));
+            }
+        });
+        false
+    }
+}
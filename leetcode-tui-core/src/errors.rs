@@ -21,6 +21,13 @@ pub enum CoreError {
 
     #[error("QuestionId: {0} does not exist")]
     QuestionIdDoesNotExist(String),
+
+    #[error("Testcase {case} has {lines} lines, the question takes {params} parameters")]
+    TestcaseParamCountError {
+        case: usize,
+        lines: usize,
+        params: usize,
+    },
 }
 
 pub type CoreResult<T> = Result<T, CoreError>;
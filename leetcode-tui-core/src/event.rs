@@ -1,4 +1,5 @@
 use crossterm::event::KeyEvent;
+use leetcode_core::types::user_status::UserStatus;
 use leetcode_tui_db::{DbQuestion, DbTopic};
 use leetcode_tui_shared::RoCell;
 use std::path::PathBuf;
@@ -25,12 +26,23 @@ pub enum Event {
         Vec<String>,
         tokio::sync::oneshot::Sender<Option<usize>>,
     ),
-    Input(super::UBStrSender, Option<String>),
+    /// Sender, default text and prompt
+    Input(super::UBStrSender, Option<String>, Option<String>),
     Open(PathBuf),
     Error(String),
-    QuestionUpdate,
+    /// A request was rejected because the user is not signed in
+    SignedOut(String),
+    /// The question as stored in the db after a change
+    QuestionUpdate(DbQuestion),
+    /// Topics and lists changed in the db
+    TopicsUpdate,
+    /// Questions changed in the db by a background sync
+    QuestionsSynced,
+    /// Ratings were stored for the questions which had none
+    RatingsUpdate,
     SyncDb,
     ProgressUpdate(String, u32, u32),
+    UserStatus(UserStatus),
 }
 
 impl Event {
@@ -90,12 +102,20 @@ macro_rules! emit {
     (Error($e:expr)) => {
         $crate::Event::Error($e).emit();
     };
+    (SignedOut($e:expr)) => {
+        $crate::Event::SignedOut($e).emit();
+    };
     (Open($e:expr)) => {
         $crate::Event::Open($e).emit();
     };
     (Input($e:expr)) => {{
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-        $crate::Event::Input(tx, $e).emit();
+        $crate::Event::Input(tx, $e, None).emit();
+        rx
+    }};
+    (Input($prompt:expr, $e:expr)) => {{
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        $crate::Event::Input(tx, $e, Some($prompt.into())).emit();
         rx
     }};
     (QuestionFilter($e:expr)) => {
@@ -104,6 +124,12 @@ macro_rules! emit {
     (ProgressUpdate($title:expr, $progress:expr, $total:expr)) => {
         $crate::Event::ProgressUpdate($title, $progress, $total).emit();
     };
+    (UserStatus($status:expr)) => {
+        $crate::Event::UserStatus($status).emit();
+    };
+    (QuestionUpdate($question:expr)) => {
+        $crate::Event::QuestionUpdate($question).emit();
+    };
     ($event:ident) => {
         $crate::Event::$event.emit();
     };
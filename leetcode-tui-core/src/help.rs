@@ -17,12 +17,23 @@ impl Default for Help {
                 vec!["j/Down", "Move to Next Question"],
                 vec!["k/Up", "Move to Previous Question"],
                 vec!["r", "Move to Random Question"],
+                vec!["d", "Open today's daily challenge"],
+                vec!["D", "Daily challenge calendar and streak"],
+                vec!["M", "Catch up on a missed daily challenge"],
                 vec!["Enter", "Read Question/Selection"],
                 vec!["e", "Open Editor"],
+                vec!["E", "Edit custom testcases"],
                 vec!["R", "Run Solution"],
                 vec!["s", "Submit Solution"],
+                vec!["x", "Stop waiting for Run/Submit result"],
+                vec!["h", "Show submission history"],
+                vec!["f", "Add to/remove from favorites"],
                 vec!["/", "Search"],
+                vec!["L", "Sort by like ratio / by id"],
+                vec!["I", "Import a question list from a .csv/.toml file"],
+                vec!["X", "Export the selected list"],
                 vec!["c", "Open config file"],
+                vec!["U", "Update session cookies"],
                 vec!["*", "Sync database!"],
             ],
             visible: Default::default(),
@@ -53,7 +64,7 @@ impl Help {
         &self.items
     }
 
-    pub fn next(&mut self) -> bool {
+    pub fn next_item(&mut self) -> bool {
         let i = match self.state.selected() {
             Some(i) => {
                 if i >= self.items.len() - 1 {
@@ -68,7 +79,7 @@ impl Help {
         true
     }
 
-    pub fn previous(&mut self) -> bool {
+    pub fn prev_item(&mut self) -> bool {
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
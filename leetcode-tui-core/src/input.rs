@@ -4,6 +4,7 @@ use crate::SendError;
 pub struct Input {
     pub visible: bool,
     current_text: Option<String>,
+    prompt: Option<String>,
     sender: Option<super::UBStrSender>,
 }
 
@@ -11,19 +12,31 @@ impl Input {
     pub fn text(&self) -> Option<&String> {
         self.current_text.as_ref()
     }
+
+    /// Shown in front of the text, defaults to the search prompt `/`
+    pub fn prompt(&self) -> &str {
+        self.prompt.as_deref().unwrap_or("/")
+    }
 }
 
 impl Input {
     pub fn close(&mut self) -> bool {
         self.current_text = None;
         if let Some(sender) = self.sender.take() {
-            tokio::spawn(async move {
-                let _ = sender.send(None).emit_if_error();
-            });
+            let _ = sender.send(None).emit_if_error();
         }
         self.toggle()
     }
 
+    /// Sends the final text and closes the channel, the receiver sees the last
+    /// `Some` value followed by the end of the stream.
+    pub fn submit(&mut self) -> bool {
+        self.try_send();
+        self.sender = None;
+        self.current_text = None;
+        self.toggle()
+    }
+
     pub fn char(&mut self, c: char) -> bool {
         if let Some(_text) = self.current_text.as_mut() {
             _text.push(c);
@@ -44,12 +57,11 @@ impl Input {
         true
     }
 
+    // sent in place so that the receiver always sees the keystrokes in order
     pub fn try_send(&mut self) {
         let text = self.current_text.clone();
-        if let Some(sender) = self.sender.clone() {
-            tokio::spawn(async move {
-                let _ = sender.send(text).emit_if_error();
-            });
+        if let Some(sender) = self.sender.as_ref() {
+            let _ = sender.send(text).emit_if_error();
         }
     }
 
@@ -58,8 +70,14 @@ impl Input {
         true
     }
 
-    pub fn reset_with(&mut self, sender: super::UBStrSender, default_input: Option<String>) {
+    pub fn reset_with(
+        &mut self,
+        sender: super::UBStrSender,
+        default_input: Option<String>,
+        prompt: Option<String>,
+    ) {
         self.sender = Some(sender);
         self.current_text = default_input;
+        self.prompt = prompt;
     }
 }
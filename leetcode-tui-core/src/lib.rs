@@ -1,6 +1,7 @@
 pub mod event;
 pub mod step;
 pub use event::Event;
+use leetcode_core::errors::LcAppError;
 use std::error::Error;
 pub mod content;
 pub mod errors;
@@ -8,6 +9,7 @@ pub mod help;
 pub mod input;
 pub mod popup;
 pub mod progress;
+pub mod session;
 pub mod utils;
 
 pub type UBStrSender = tokio::sync::mpsc::UnboundedSender<Option<String>>;
@@ -18,12 +20,20 @@ pub trait SendError<T, E> {
 
 impl<T, E> SendError<T, E> for Result<T, E>
 where
-    E: Error + Sized,
+    E: Error + Sized + 'static,
 {
     fn emit_if_error(self) -> Result<T, E> {
         match self {
             Err(e) => {
-                emit!(Error(e.to_string()));
+                let signed_out = matches!(
+                    (&e as &dyn Error).downcast_ref::<LcAppError>(),
+                    Some(LcAppError::Unauthenticated(_) | LcAppError::CookiesExpiredError)
+                );
+                if signed_out {
+                    emit!(SignedOut(e.to_string()));
+                } else {
+                    emit!(Error(e.to_string()));
+                }
                 Err(e)
             }
             ok => ok,
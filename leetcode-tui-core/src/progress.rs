@@ -21,11 +21,7 @@ impl ProgressCtx {
         self.progress = progress;
         self.total = total;
         self.title = title;
-        if self.progress == total || self.progress == 0 {
-            self.is_visible = false;
-        } else {
-            self.is_visible = true;
-        }
+        self.is_visible = !(self.progress == total || self.progress == 0);
     }
 
     pub fn get_title(&self) -> &str {
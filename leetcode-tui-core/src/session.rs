@@ -0,0 +1,124 @@
+use crate::{emit, SendError};
+use leetcode_core::errors::LcAppError;
+use leetcode_core::types::user_status::UserStatus;
+use leetcode_core::{GQLLeetcodeRequest, UserStatusRequest};
+use leetcode_tui_config::{log, save_credentials, CONFIG};
+use std::sync::Mutex;
+
+/// Cookies leetcode accepted last, `None` while the ones from config.toml are in use.
+static ACCEPTED_CREDENTIALS: Mutex<Option<(String, String)>> = Mutex::new(None);
+
+/// Who is signed in with the configured cookies, shown in the status bar.
+#[derive(Default)]
+pub struct Session {
+    status: Option<UserStatus>,
+}
+
+impl Session {
+    /// `None` until the first check came back.
+    pub fn status(&self) -> Option<&UserStatus> {
+        self.status.as_ref()
+    }
+
+    pub fn set_status(&mut self, status: UserStatus) -> bool {
+        self.status = Some(status);
+        true
+    }
+
+    /// Checks the cookies from config.toml, asks for new ones when they are not valid.
+    pub fn validate() {
+        tokio::spawn(async move {
+            match fetch_user_status().await {
+                Ok(status) if status.is_signed_in => {
+                    emit!(UserStatus(status));
+                }
+                Ok(status) => {
+                    emit!(UserStatus(status));
+                    prompt_credentials().await;
+                }
+                Err(e) => log::error!("could not check the session cookies: {e}"),
+            }
+        });
+    }
+
+    pub fn update_credentials(&self) -> bool {
+        tokio::spawn(prompt_credentials());
+        false
+    }
+}
+
+/// Expired cookies are reported as a signed out user rather than as an error.
+async fn fetch_user_status() -> Result<UserStatus, LcAppError> {
+    match UserStatusRequest::new().send().await {
+        Ok(response) => Ok(response.data.user_status),
+        Err(LcAppError::CookiesExpiredError | LcAppError::Unauthenticated(_)) => {
+            Ok(UserStatus::default())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Last text entered, `None` when the input was dismissed with Esc.
+pub(crate) async fn read_input(prompt: &str, default: Option<String>) -> Option<String> {
+    let mut rx = emit!(Input(prompt, default));
+    let mut text = None;
+    while let Some(maybe_text) = rx.recv().await {
+        maybe_text.as_ref()?;
+        text = maybe_text;
+    }
+    text.map(|t| t.trim().to_string()).filter(|t| !t.is_empty())
+}
+
+async fn prompt_credentials() {
+    let Some(csrftoken) = read_input("csrftoken: ", None).await else {
+        return;
+    };
+    let Some(lc_session) = read_input("LEETCODE_SESSION: ", None).await else {
+        return;
+    };
+    if leetcode_core::set_credentials(&csrftoken, &lc_session)
+        .await
+        .emit_if_error()
+        .is_err()
+    {
+        return;
+    }
+    let Ok(status) = fetch_user_status().await.emit_if_error() else {
+        restore_credentials().await;
+        return;
+    };
+    if !status.is_signed_in {
+        restore_credentials().await;
+        emit!(Error(
+            "Leetcode did not accept these cookies, press U to enter them again.".into()
+        ));
+        return;
+    }
+    let username = status.username().to_string();
+    emit!(UserStatus(status));
+    *ACCEPTED_CREDENTIALS.lock().unwrap() = Some((csrftoken.clone(), lc_session.clone()));
+    if let Err(e) = save_credentials(&csrftoken, &lc_session) {
+        emit!(Error(format!(
+            "Signed in, but config.toml could not be updated: {e}"
+        )));
+        return;
+    }
+    emit!(Popup(
+        "Signed in",
+        vec![format!(
+            "Signed in as {username}, the cookies are saved to config.toml."
+        )]
+    ));
+}
+
+/// Goes back to the cookies in use before the rejected ones were entered.
+async fn restore_credentials() {
+    let accepted = ACCEPTED_CREDENTIALS.lock().unwrap().clone();
+    let (csrftoken, lc_session) = accepted.unwrap_or_else(|| {
+        let config = CONFIG.as_ref();
+        (config.csrftoken.clone(), config.lc_session.clone())
+    });
+    let _ = leetcode_core::set_credentials(&csrftoken, &lc_session)
+        .await
+        .emit_if_error();
+}
@@ -19,6 +19,15 @@ pub enum DbErr {
 
     #[error("Topic not found: {0}")]
     TopicsNotFoundInDb(String),
+
+    #[error("Could not read or write the list file: {0}")]
+    ListFileError(#[from] std::io::Error),
+
+    #[error("Malformed list file: {0}")]
+    ListFileFormatError(String),
+
+    #[error("Could not migrate the db: {0}")]
+    MigrationError(String),
 }
 
 pub type DBResult<T> = Result<T, DbErr>;
@@ -1,9 +1,13 @@
 pub mod errors;
+pub mod migration;
 pub mod models;
 use errors::DBResult;
 use leetcode_core as api;
-pub use models::{question::DbQuestion, topic::DbTopic};
-use models::{QuestionTopicMap, TopicQuestionMap};
+pub use models::{
+    daily_challenge::DbDailyChallenge, favorite::Favorites, index::QuestionIndex, list::DbList,
+    question::DbQuestion, rating::DbQuestionRating, submission::DbSubmission, topic::DbTopic,
+};
+use models::{ListQuestionMap, QuestionTopicMap, TopicQuestionMap};
 use native_db::Database;
 use native_db::DatabaseBuilder;
 use native_db::Input;
@@ -20,44 +24,64 @@ pub(crate) fn get_db_client() -> &'static Database<'static> {
 }
 
 pub fn define_schema(db_builder: &mut DatabaseBuilder) -> errors::DBResult<&mut DatabaseBuilder> {
+    db_builder.define::<models::question::v1::DbQuestion>()?;
     db_builder.define::<DbQuestion>()?;
     db_builder.define::<DbTopic>()?;
     db_builder.define::<QuestionTopicMap>()?;
     db_builder.define::<TopicQuestionMap>()?;
+    db_builder.define::<DbSubmission>()?;
+    db_builder.define::<DbQuestionRating>()?;
+    db_builder.define::<DbDailyChallenge>()?;
+    db_builder.define::<DbList>()?;
+    db_builder.define::<ListQuestionMap>()?;
+    db_builder.define::<migration::DbSchemaVersion>()?;
+    db_builder.define::<models::question::sync::DbSyncState>()?;
     Ok(db_builder)
 }
 
-pub fn init(db_path: Option<&PathBuf>) {
-    let mut database_builder = DatabaseBuilder::new();
-    define_schema(&mut database_builder).expect("DB schema initialization failed.");
+pub fn init(db_path: Option<&PathBuf>) -> DBResult<()> {
+    init_with_progress(db_path, |_| {})
+}
 
-    DB_BUILDER.get_or_init(|| database_builder);
+/// Same as [`init`], `on_progress` is told about each pending migration. A db
+/// written by a newer release or a failed migration is reported as an error.
+pub fn init_with_progress(
+    db_path: Option<&PathBuf>,
+    on_progress: impl FnMut(migration::MigrationProgress),
+) -> DBResult<()> {
+    if DB_CLIENT.get().is_some() {
+        return Ok(());
+    }
+    let builder = match DB_BUILDER.get() {
+        Some(builder) => builder,
+        None => {
+            let mut database_builder = DatabaseBuilder::new();
+            define_schema(&mut database_builder)?;
+            DB_BUILDER.get_or_init(|| database_builder)
+        }
+    };
 
-    DB_CLIENT.get_or_init(|| {
-        if let Some(path) = db_path {
-            DB_BUILDER
-                .get()
-                .unwrap()
-                .create(path)
-                .expect("Error while creating db conn.")
-        } else {
-            DB_BUILDER
-                .get()
-                .unwrap()
-                .create_in_memory()
-                .expect("Error while creating db conn in memory.")
+    let db = match db_path {
+        Some(path) => migration::open(builder, path, on_progress)?,
+        None => {
+            let db = builder.create_in_memory()?;
+            migration::run(&db, true, on_progress)?;
+            db
         }
-    });
+    };
+    // another thread may have won the race, its db is kept
+    DB_CLIENT.set(db).ok();
+    Ok(())
 }
 
-fn save<'a, T: Input + Clone>(item: &T) -> DBResult<()> {
+fn save<T: Input + Clone>(item: &T) -> DBResult<()> {
     let rw = get_db_client().rw_transaction()?;
     rw.insert(item.clone())?;
     rw.commit()?;
     Ok(())
 }
 
-fn save_multiple<'a, T: Input + Clone>(items: &Vec<T>) -> DBResult<()> {
+fn save_multiple<T: Input + Clone>(items: &Vec<T>) -> DBResult<()> {
     let rw = get_db_client().rw_transaction()?;
     for item in items {
         rw.insert(item.clone())?;
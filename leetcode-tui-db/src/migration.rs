@@ -0,0 +1,186 @@
+//! Ordered upgrades of the db file, applied at [`crate::init`].
+//!
+//! Changing a stored model goes like this:
+//!
+//! 1. move the current struct to a `vN` module next to the model, keeping its
+//!    `native_model` version, and implement `From<Model> for vN::Model`
+//! 2. bump the version of the model and point it to the old one with
+//!    `#[native_model(id = .., version = N + 1, from = vN::Model)]`
+//! 3. define both in [`crate::define_schema`]
+//! 4. append a [`Migration`] to [`MIGRATIONS`] which calls [`upgrade`]
+//!
+//! The version reached is stored in the db, a file opened by an older release
+//! runs the pending migrations only.
+use crate::errors::{DBResult, DbErr};
+use crate::models::question::{v1, DbQuestion};
+use crate::Db;
+use native_db::transaction::RwTransaction;
+use native_db::*;
+use native_model::{native_model, Model};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const SCHEMA_VERSION_KEY: &str = "schema";
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 11, version = 1)]
+#[native_db]
+pub(crate) struct DbSchemaVersion {
+    #[primary_key]
+    key: String,
+    version: u32,
+}
+
+#[derive(Debug)]
+pub struct Migration {
+    /// Schema version reached once applied
+    pub version: u32,
+    pub description: &'static str,
+    run: fn(&RwTransaction) -> DBResult<()>,
+}
+
+pub static MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    description: "Store acceptance rate, frequency and solution flags of the questions",
+    run: |rw| upgrade::<v1::DbQuestion, DbQuestion>(rw),
+}];
+
+/// Version of a db with every migration applied.
+pub fn current_version() -> u32 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or_default()
+}
+
+/// Reported before each migration is applied.
+#[derive(Debug)]
+pub struct MigrationProgress<'a> {
+    /// Starts at 1
+    pub step: usize,
+    pub total: usize,
+    pub migration: &'a Migration,
+}
+
+/// Moves every record of the old version of a model to the new one.
+// `rw.migrate` of native_db 0.5 gives up as soon as any other table has data,
+// hence the old version is drained and converted by hand.
+pub fn upgrade<Old, New>(rw: &RwTransaction) -> DBResult<()>
+where
+    Old: Input,
+    New: Input + From<Old>,
+{
+    for item in rw.drain().primary::<Old>()? {
+        rw.insert(New::from(item))?;
+    }
+    Ok(())
+}
+
+pub fn stored_version(db: &Db) -> DBResult<Option<u32>> {
+    let r = db.r_transaction()?;
+    Ok(r.get()
+        .primary::<DbSchemaVersion>(SCHEMA_VERSION_KEY.to_string())?
+        .map(|v| v.version))
+}
+
+fn set_version(rw: &RwTransaction, version: u32) -> DBResult<()> {
+    rw.insert(DbSchemaVersion {
+        key: SCHEMA_VERSION_KEY.into(),
+        version,
+    })?;
+    Ok(())
+}
+
+/// `<file>.v<version>.bak` next to the db file
+pub fn backup_path(db_path: &Path, version: u32) -> PathBuf {
+    let mut file_name = db_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".v{version}.bak"));
+    db_path.with_file_name(file_name)
+}
+
+/// `<file>.bak.tmp` next to the db file, the state before it was opened
+fn unopened_copy_path(db_path: &Path) -> PathBuf {
+    let mut file_name = db_path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".bak.tmp");
+    db_path.with_file_name(file_name)
+}
+
+/// Version the migrations start from, `None` for a new db. A db written before
+/// the versions were recorded starts from 0.
+fn starting_version(db: &Db, is_new: bool) -> DBResult<Option<u32>> {
+    let stored = match stored_version(db)? {
+        Some(version) => version,
+        None if is_new => return Ok(None),
+        None => 0,
+    };
+    let current = current_version();
+    if stored > current {
+        return Err(DbErr::MigrationError(format!(
+            "the db is at schema version {stored}, this release supports up to {current}"
+        )));
+    }
+    Ok(Some(stored))
+}
+
+/// Opens the db file and brings it to [`current_version`]. The file is copied
+/// before it is opened, the copy is kept at [`backup_path`] when migrations are
+/// pending and removed otherwise.
+pub fn open<'a>(
+    builder: &'a DatabaseBuilder,
+    path: &Path,
+    on_progress: impl FnMut(MigrationProgress),
+) -> DBResult<Db<'a>> {
+    let is_new = !path.exists();
+    let copy = unopened_copy_path(path);
+    let backup_error =
+        |e| DbErr::MigrationError(format!("could not back up {}: {e}", path.display()));
+    if !is_new {
+        std::fs::copy(path, &copy).map_err(backup_error)?;
+    }
+
+    let opened = builder
+        .create(path)
+        .map_err(DbErr::from)
+        .and_then(|db| Ok((starting_version(&db, is_new)?, db)));
+    let kept = match &opened {
+        Ok((Some(from), _)) if !is_new && *from < current_version() => {
+            std::fs::rename(&copy, backup_path(path, *from)).map_err(backup_error)
+        }
+        _ => {
+            if !is_new {
+                std::fs::remove_file(&copy).ok();
+            }
+            Ok(())
+        }
+    };
+    let (_, db) = opened?;
+    kept?;
+    run(&db, is_new, on_progress)?;
+    Ok(db)
+}
+
+/// Brings the db to [`current_version`]. A new db, `is_new`, only gets the
+/// version recorded. See [`open`] for the backup of a db file.
+pub fn run(db: &Db, is_new: bool, mut on_progress: impl FnMut(MigrationProgress)) -> DBResult<()> {
+    let Some(stored) = starting_version(db, is_new)? else {
+        let rw = db.rw_transaction()?;
+        set_version(&rw, current_version())?;
+        rw.commit()?;
+        return Ok(());
+    };
+
+    let pending = MIGRATIONS
+        .iter()
+        .filter(|m| m.version > stored)
+        .collect::<Vec<_>>();
+    for (i, migration) in pending.iter().enumerate() {
+        on_progress(MigrationProgress {
+            step: i + 1,
+            total: pending.len(),
+            migration,
+        });
+        // an interrupted run resumes from the last committed migration
+        let rw = db.rw_transaction()?;
+        (migration.run)(&rw)?;
+        set_version(&rw, migration.version)?;
+        rw.commit()?;
+    }
+    Ok(())
+}
@@ -1,12 +1,20 @@
+use native_db::transaction::RwTransaction;
 use native_db::*;
 use native_model::{native_model, Model};
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 
-use crate::{errors::DBResult, get_db_client, save};
+use crate::{errors::DBResult, get_db_client};
 
 use self::topic::DbTopic;
+pub mod daily_challenge;
+pub mod favorite;
+pub mod index;
+pub mod list;
+pub mod list_file;
 pub mod question;
+pub mod rating;
+pub mod submission;
 pub mod topic;
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
@@ -21,7 +29,7 @@ pub(crate) struct TopicQuestionMap {
 }
 
 impl TopicQuestionMap {
-    fn new(topic_id: &str, question_id: u32) -> Self {
+    pub(crate) fn new(topic_id: &str, question_id: u32) -> Self {
         Self {
             id: format!("{topic_id}_{question_id}"),
             topic_id: topic_id.to_string(),
@@ -29,19 +37,6 @@ impl TopicQuestionMap {
         }
     }
 
-    pub(crate) fn save_mapping<'a>(question: &question::DbQuestion) -> DBResult<()> {
-        for topic in question.get_topics() {
-            let topic_question_mapping = Self::new(&topic.slug, question.id);
-            topic_question_mapping.save_to_db()?;
-        }
-        Ok(())
-    }
-
-    fn save_to_db<'a>(&self) -> DBResult<()> {
-        save(self)?;
-        Ok(())
-    }
-
     pub(crate) fn get_all_question_by_topic(topic: &DbTopic) -> DBResult<Vec<u32>> {
         let trans = get_db_client().r_transaction()?;
         let mut quests = vec![];
@@ -49,6 +44,8 @@ impl TopicQuestionMap {
             .scan()
             .secondary::<Self>(TopicQuestionMapKey::topic_id)?
             .start_with(topic.slug.to_string())
+            // the scan matches by prefix, `string` would yield `string-matching` too
+            .filter(|m| m.topic_id == topic.slug)
         {
             quests.push(tq_map.question_id);
         }
@@ -68,24 +65,70 @@ pub(crate) struct QuestionTopicMap {
 }
 
 impl QuestionTopicMap {
-    fn new(question_id: u32, topic_id: &str) -> Self {
+    pub(crate) fn new(question_id: u32, topic_id: &str) -> Self {
         Self {
             id: format!("{question_id}_{topic_id}"),
             question_id,
             topic_id: topic_id.to_string(),
         }
     }
+}
+
+/// Membership of a question in a [`list::DbList`], `position` keeps the order
+/// of the list.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 9, version = 1)]
+#[native_db]
+pub(crate) struct ListQuestionMap {
+    #[primary_key]
+    id: String,
+    #[secondary_key]
+    list_id: String,
+    question_id: u32,
+    position: u32,
+}
+
+impl ListQuestionMap {
+    fn new(list_id: &str, question_id: u32, position: u32) -> Self {
+        Self {
+            id: format!("{list_id}_{question_id}"),
+            list_id: list_id.to_string(),
+            question_id,
+            position,
+        }
+    }
 
-    pub(crate) fn save_mapping<'a>(question: &question::DbQuestion) -> DBResult<()> {
-        for topic in question.get_topics() {
-            let question_topic_mapping = Self::new(question.id, &topic.slug);
-            question_topic_mapping.save_to_db()?;
+    /// Replaces the questions of the list, committed along with the caller's changes.
+    pub(crate) fn replace_list(
+        rw: &RwTransaction,
+        list_id: &str,
+        question_ids: &[u32],
+    ) -> DBResult<()> {
+        let stale = rw
+            .scan()
+            .secondary::<Self>(ListQuestionMapKey::list_id)?
+            .start_with(list_id.to_string())
+            .filter(|m| m.list_id == list_id)
+            .collect::<Vec<_>>();
+        for mapping in stale {
+            rw.remove(mapping)?;
+        }
+        for (position, question_id) in question_ids.iter().enumerate() {
+            rw.insert(Self::new(list_id, *question_id, position as u32))?;
         }
         Ok(())
     }
 
-    fn save_to_db<'a>(&self) -> DBResult<()> {
-        save(self)?;
-        Ok(())
+    pub(crate) fn get_all_question_by_list(list_id: &str) -> DBResult<Vec<u32>> {
+        let trans = get_db_client().r_transaction()?;
+        let mut mappings = trans
+            .scan()
+            .secondary::<Self>(ListQuestionMapKey::list_id)?
+            .start_with(list_id.to_string())
+            // the scan matches by prefix, `a` would yield the mappings of `ab` too
+            .filter(|m| m.list_id == list_id)
+            .collect::<Vec<_>>();
+        mappings.sort_by_key(|m| m.position);
+        Ok(mappings.into_iter().map(|m| m.question_id).collect())
     }
 }
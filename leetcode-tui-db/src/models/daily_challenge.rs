@@ -0,0 +1,88 @@
+use super::*;
+use crate::{
+    api::types::daily_challenge_calendar::Challenge,
+    errors::{DBResult, DbErr},
+    get_db_client, save_multiple,
+};
+use leetcode_tui_shared::date::{civil_from_days, days_from_civil, format_date};
+use std::collections::HashMap;
+
+/// A day of the daily challenge calendar and whether the user solved it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 7, version = 1)]
+#[native_db]
+pub struct DbDailyChallenge {
+    /// `YYYY-MM-DD`
+    #[primary_key]
+    pub date: String,
+    pub question_id: u32,
+    pub title: String,
+    pub title_slug: String,
+    pub completed: bool,
+}
+
+impl TryFrom<&Challenge> for DbDailyChallenge {
+    type Error = DbErr;
+
+    fn try_from(value: &Challenge) -> Result<Self, Self::Error> {
+        Ok(Self {
+            date: value.date.clone(),
+            question_id: value.question.question_frontend_id.parse()?,
+            title: value.question.title.clone(),
+            title_slug: value.question.title_slug.clone(),
+            completed: value.is_completed(),
+        })
+    }
+}
+
+impl DbDailyChallenge {
+    pub fn save_multiple_to_db(challenges: &Vec<Self>) -> DBResult<()> {
+        save_multiple(challenges)
+    }
+
+    pub fn get_by_date(date: &str) -> DBResult<Option<Self>> {
+        let r = get_db_client().r_transaction()?;
+        Ok(r.get().primary::<Self>(date.to_string())?)
+    }
+
+    /// Stored challenges of the month ordered by date, `month` starts at 1.
+    pub fn fetch_month(year: i32, month: u32) -> DBResult<Vec<Self>> {
+        let r = get_db_client().r_transaction()?;
+        let challenges = r
+            .scan()
+            .primary::<Self>()?
+            .start_with(format!("{year:04}-{month:02}-"))
+            .collect();
+        Ok(challenges)
+    }
+
+    /// Consecutive solved days up to `today`. An unsolved `today` does not
+    /// break the streak yet, counting starts from the day before.
+    pub fn current_streak(today: (i32, u32, u32)) -> DBResult<u32> {
+        let r = get_db_client().r_transaction()?;
+        let completed: HashMap<String, bool> = r
+            .scan()
+            .primary::<Self>()?
+            .all()
+            .map(|c| (c.date, c.completed))
+            .collect();
+        let is_completed = |days: i64| {
+            let (y, m, d) = civil_from_days(days);
+            completed
+                .get(&format_date(y, m, d))
+                .copied()
+                .unwrap_or(false)
+        };
+
+        let mut day = days_from_civil(today.0, today.1, today.2);
+        if !is_completed(day) {
+            day -= 1;
+        }
+        let mut streak = 0;
+        while is_completed(day) {
+            streak += 1;
+            day -= 1;
+        }
+        Ok(streak)
+    }
+}
@@ -0,0 +1,51 @@
+use super::{question::DbQuestion, topic::DbTopic};
+use crate::{
+    errors::{DBResult, DbErr},
+    get_db_client,
+};
+
+/// Slug of the pseudo topic listing the favorite questions.
+pub const FAVORITES_TOPIC: &str = "favorites";
+
+/// The questions in the default favorite list of leetcode. `is_favor` of the
+/// stored questions is the only record of them, the sync keeps it up to date.
+pub struct Favorites;
+
+impl Favorites {
+    pub fn topic() -> DbTopic {
+        DbTopic::new(FAVORITES_TOPIC)
+    }
+
+    pub fn is_favorite(question_id: u32) -> DBResult<bool> {
+        let r = get_db_client().r_transaction()?;
+        let question: Option<DbQuestion> = r.get().primary(question_id)?;
+        Ok(question.is_some_and(|q| q.is_favor))
+    }
+
+    /// Setting the current state again is a no-op. Returns the stored question.
+    pub fn set_favorite(question_id: u32, favorite: bool) -> DBResult<DbQuestion> {
+        let rw = get_db_client().rw_transaction()?;
+        let question: DbQuestion = rw
+            .get()
+            .primary(question_id)?
+            .ok_or(DbErr::QuestionsNotFoundInDb(question_id.to_string()))?;
+        if question.is_favor == favorite {
+            return Ok(question);
+        }
+        let mut updated = question.clone();
+        updated.is_favor = favorite;
+        rw.update(question, updated.clone())?;
+        rw.commit()?;
+        Ok(updated)
+    }
+
+    pub fn fetch_questions() -> DBResult<Vec<DbQuestion>> {
+        let mut questions = DbQuestion::fetch_all()?;
+        questions.retain(|q| q.is_favor);
+        Ok(questions)
+    }
+
+    pub fn fetch_ids_sorted() -> DBResult<Vec<u32>> {
+        Ok(Self::fetch_questions()?.iter().map(|q| q.id).collect())
+    }
+}
@@ -0,0 +1,100 @@
+use super::{favorite::FAVORITES_TOPIC, question::DbQuestion, topic::DbTopic};
+use crate::errors::DBResult;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+/// The questions held in memory, switching topics then needs no db reads
+/// except for the membership of lists.
+#[derive(Default)]
+pub struct QuestionIndex {
+    questions: BTreeMap<u32, Rc<DbQuestion>>,
+    /// Question ids by topic slug, ascending. The favorites are listed under
+    /// [`FAVORITES_TOPIC`].
+    by_topic: HashMap<String, Vec<u32>>,
+}
+
+/// The topics a question is listed under in the index.
+fn indexed_slugs(question: &DbQuestion) -> impl Iterator<Item = &str> {
+    let favorite = question.is_favor.then_some(FAVORITES_TOPIC);
+    question
+        .topics
+        .iter()
+        .map(|topic| topic.slug.as_str())
+        .chain(favorite)
+}
+
+impl QuestionIndex {
+    /// Loads every question in a single scan.
+    pub fn build() -> DBResult<Self> {
+        let mut index = Self::default();
+        for question in DbQuestion::fetch_all()? {
+            index.insert(question);
+        }
+        Ok(index)
+    }
+
+    pub fn rebuild(&mut self) -> DBResult<()> {
+        *self = Self::build()?;
+        Ok(())
+    }
+
+    /// Adds the question or replaces the stored copy.
+    pub fn insert(&mut self, question: DbQuestion) {
+        if let Some(old) = self.questions.get(&question.id) {
+            for slug in indexed_slugs(old) {
+                if let Some(ids) = self.by_topic.get_mut(slug) {
+                    ids.retain(|id| *id != question.id);
+                }
+            }
+        }
+        for slug in indexed_slugs(&question) {
+            let ids = self.by_topic.entry(slug.to_string()).or_default();
+            if let Err(pos) = ids.binary_search(&question.id) {
+                ids.insert(pos, question.id);
+            }
+        }
+        self.questions.insert(question.id, Rc::new(question));
+    }
+
+    pub fn len(&self) -> usize {
+        self.questions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.questions.is_empty()
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Rc<DbQuestion>> {
+        self.questions.get(&id)
+    }
+
+    pub fn favorites_count(&self) -> usize {
+        self.by_topic.get(FAVORITES_TOPIC).map_or(0, Vec::len)
+    }
+
+    /// Accepted and total questions among `ids`, the unknown ones are skipped.
+    pub fn progress(&self, ids: &[u32]) -> (usize, usize) {
+        let questions = ids.iter().filter_map(|id| self.get(*id));
+        questions.fold((0, 0), |(accepted, total), q| {
+            let is_accepted = q.status.as_deref() == Some("ac");
+            (accepted + usize::from(is_accepted), total + 1)
+        })
+    }
+
+    pub fn questions(&self, topic: &DbTopic) -> DBResult<Vec<Rc<DbQuestion>>> {
+        if topic.slug == "all" {
+            return Ok(self.questions.values().cloned().collect());
+        }
+        let resolve = |ids: &[u32]| {
+            ids.iter()
+                .filter_map(|id| self.questions.get(id).cloned())
+                .collect()
+        };
+        match self.by_topic.get(&topic.slug) {
+            Some(ids) => Ok(resolve(ids)),
+            None if topic.slug == FAVORITES_TOPIC => Ok(vec![]),
+            // lists, their membership is not indexed
+            None => Ok(resolve(&topic.question_ids()?.unwrap_or_default())),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::{question::DbQuestion, topic::DbTopic, *};
+use crate::{
+    errors::{DBResult, DbErr},
+    get_db_client,
+};
+
+/// Slug prefix of the lists shown among the topics.
+pub const LIST_TOPIC_PREFIX: &str = "list:";
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ListKind {
+    StudyPlan,
+    Favorite,
+    /// Imported from a local file
+    Custom,
+}
+
+/// A curated question list, e.g. the `leetcode-75` study plan or a favorite
+/// list known by its `listId`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 8, version = 1)]
+#[native_db]
+pub struct DbList {
+    #[primary_key]
+    pub id: String,
+    pub name: String,
+    pub kind: ListKind,
+}
+
+impl DbList {
+    pub fn new(id: &str, name: &str, kind: ListKind) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            kind,
+        }
+    }
+
+    /// Stores the list, the questions replace the ones saved before.
+    pub fn save_with_questions(&self, question_ids: &[u32]) -> DBResult<()> {
+        let rw = get_db_client().rw_transaction()?;
+        ListQuestionMap::replace_list(&rw, &self.id, question_ids)?;
+        rw.insert(self.clone())?;
+        rw.commit()?;
+        Ok(())
+    }
+
+    pub fn fetch_all() -> DBResult<Vec<Self>> {
+        let r = get_db_client().r_transaction()?;
+        let lists = r.scan().primary::<Self>()?.all().collect();
+        Ok(lists)
+    }
+
+    pub fn get_list_by_id(id: &str) -> DBResult<Self> {
+        let r = get_db_client().r_transaction()?;
+        r.get()
+            .primary(id.to_string())?
+            .ok_or(DbErr::TopicsNotFoundInDb(id.to_string()))
+    }
+
+    /// Ids in the order of the list, including the questions missing in the db.
+    pub fn question_ids(&self) -> DBResult<Vec<u32>> {
+        ListQuestionMap::get_all_question_by_list(&self.id)
+    }
+
+    /// Questions in the order of the list, the ones missing in the db are skipped.
+    pub fn fetch_questions(&self) -> DBResult<Vec<DbQuestion>> {
+        DbQuestion::get_questions_by_ids(&self.question_ids()?)
+    }
+
+    /// Accepted and total questions of the list.
+    pub fn progress(&self) -> DBResult<(usize, usize)> {
+        let questions = self.fetch_questions()?;
+        let accepted = questions
+            .iter()
+            .filter(|q| q.status.as_deref() == Some("ac"))
+            .count();
+        Ok((accepted, questions.len()))
+    }
+
+    /// The entry of the list in the topic pane.
+    pub fn as_topic(&self) -> DbTopic {
+        DbTopic::new(&format!("{LIST_TOPIC_PREFIX}{}", self.id))
+    }
+}
@@ -0,0 +1,183 @@
+//! Question lists kept as local files, e.g. Blind 75 or a company specific set.
+//!
+//! CSV files hold one question per row, the first column being the id or the
+//! slug, a header row is optional. TOML files look like
+//!
+//! ```toml
+//! name = "Blind 75"
+//! questions = ["two-sum", 121, "3"]
+//! ```
+use super::list::{DbList, ListKind};
+use super::question::DbQuestion;
+use crate::errors::{DBResult, DbErr};
+use crate::get_db_client;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+const CSV_HEADER: &str = "id,slug,title";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+    Csv,
+    Toml,
+}
+
+impl ListFormat {
+    pub fn from_path(path: &Path) -> DBResult<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Ok(Self::Csv),
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Ok(Self::Toml),
+            _ => Err(DbErr::ListFileFormatError(format!(
+                "{} is neither a .csv nor a .toml file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TomlList {
+    name: Option<String>,
+    questions: Vec<toml::Value>,
+}
+
+#[derive(Debug)]
+pub struct ImportReport {
+    pub list: DbList,
+    pub imported: usize,
+    /// Entries which matched no question in the db, as written in the file
+    pub unknown: Vec<String>,
+}
+
+impl DbList {
+    /// Saves the questions listed in the file as a custom list. The name falls
+    /// back to the `name` of a TOML file and then to the file name.
+    pub fn import(path: &Path, name: Option<&str>) -> DBResult<ImportReport> {
+        let format = ListFormat::from_path(path)?;
+        let contents = std::fs::read_to_string(path)?;
+        let (file_name, entries) = match format {
+            ListFormat::Csv => (None, parse_csv(&contents)),
+            ListFormat::Toml => parse_toml(&contents)?,
+        };
+        let name = name
+            .map(String::from)
+            .or(file_name)
+            .or_else(|| {
+                path.file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+            })
+            .unwrap_or_else(|| "custom".into());
+
+        let (question_ids, unknown) = resolve(&entries)?;
+        let list = DbList::new(&custom_list_id(&name), &name, ListKind::Custom);
+        list.save_with_questions(&question_ids)?;
+        Ok(ImportReport {
+            list,
+            imported: question_ids.len(),
+            unknown,
+        })
+    }
+
+    /// Writes the questions of the list in the format given by the extension.
+    pub fn export(&self, path: &Path) -> DBResult<()> {
+        let questions = self.fetch_questions()?;
+        let contents = match ListFormat::from_path(path)? {
+            ListFormat::Csv => {
+                let mut lines = vec![CSV_HEADER.to_string()];
+                lines.extend(
+                    questions
+                        .iter()
+                        .map(|q| format!("{},{},{}", q.id, q.title_slug, csv_field(&q.title))),
+                );
+                lines.join("\n") + "\n"
+            }
+            ListFormat::Toml => toml::to_string(&TomlList {
+                name: Some(self.name.clone()),
+                questions: questions
+                    .iter()
+                    .map(|q| toml::Value::String(q.title_slug.clone()))
+                    .collect(),
+            })
+            .map_err(|e| DbErr::ListFileFormatError(e.to_string()))?,
+        };
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+fn custom_list_id(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    format!("custom-{slug}")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn parse_csv(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(|line| line.split(',').next().unwrap_or_default().trim())
+        .map(|entry| entry.trim_matches('"').to_string())
+        .filter(|entry| !entry.is_empty() && !entry.starts_with('#'))
+        .enumerate()
+        // a header row names the column instead of a question
+        .filter(|(i, entry)| {
+            *i > 0 || !matches!(entry.to_lowercase().as_str(), "id" | "slug" | "question")
+        })
+        .map(|(_, entry)| entry)
+        .collect()
+}
+
+fn parse_toml(contents: &str) -> DBResult<(Option<String>, Vec<String>)> {
+    let list: TomlList =
+        toml::from_str(contents).map_err(|e| DbErr::ListFileFormatError(e.to_string()))?;
+    let entries = list
+        .questions
+        .into_iter()
+        .map(|value| match value {
+            toml::Value::String(s) => s,
+            other => other.to_string(),
+        })
+        .collect();
+    Ok((list.name, entries))
+}
+
+/// Ids of the known questions in the order of the entries, and the unknown entries.
+fn resolve(entries: &[String]) -> DBResult<(Vec<u32>, Vec<String>)> {
+    let r = get_db_client().r_transaction()?;
+    let mut ids_by_slug = HashMap::new();
+    let mut known_ids = HashSet::new();
+    for question in r.scan().primary::<DbQuestion>()?.all() {
+        known_ids.insert(question.id);
+        ids_by_slug.insert(question.title_slug, question.id);
+    }
+
+    let mut question_ids = vec![];
+    let mut unknown = vec![];
+    let mut seen = HashSet::new();
+    for entry in entries {
+        let id = match entry.parse::<u32>() {
+            Ok(id) => known_ids.contains(&id).then_some(id),
+            Err(_) => ids_by_slug.get(entry.as_str()).copied(),
+        };
+        match id {
+            Some(id) => {
+                if seen.insert(id) {
+                    question_ids.push(id);
+                }
+            }
+            None => unknown.push(entry.clone()),
+        }
+    }
+    Ok((question_ids, unknown))
+}
@@ -2,12 +2,14 @@ use super::{topic::DbTopic, *};
 use crate::{
     api::types::problemset_question_list::Question,
     errors::{DBResult, DbErr},
-    get_db_client, save, save_multiple,
+    get_db_client,
 };
 use std::fmt::Display;
+pub mod sync;
+pub mod v1;
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[native_model(id = 1, version = 1)]
+#[native_model(id = 1, version = 2, from = v1::DbQuestion)]
 #[native_db]
 pub struct DbQuestion {
     #[primary_key]
@@ -18,6 +20,30 @@ pub struct DbQuestion {
     pub paid_only: bool,
     pub status: Option<String>,
     pub topics: Vec<DbTopic>,
+    /// Acceptance rate in percent
+    pub ac_rate: Option<f64>,
+    /// Frequency in interviews, premium only
+    pub freq_bar: Option<f64>,
+    pub has_solution: bool,
+    pub has_video_solution: bool,
+    pub is_favor: bool,
+}
+
+/// Questions stored before v2 are upgraded in place, the new fields are
+/// filled in by the next sync.
+impl From<v1::DbQuestion> for DbQuestion {
+    fn from(value: v1::DbQuestion) -> Self {
+        let mut question = Self::new(
+            value.id,
+            &value.title,
+            &value.title_slug,
+            value.difficulty,
+            value.paid_only,
+            value.status,
+        );
+        question.topics = value.topics;
+        question
+    }
 }
 
 impl Ord for DbQuestion {
@@ -28,7 +54,7 @@ impl Ord for DbQuestion {
 
 impl PartialOrd for DbQuestion {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.id.partial_cmp(&other.id)
+        Some(self.cmp(other))
     }
 }
 
@@ -70,14 +96,21 @@ impl TryFrom<Question> for DbQuestion {
     fn try_from(
         value: crate::api::types::problemset_question_list::Question,
     ) -> Result<Self, Self::Error> {
+        // leetcode.cn users would rather see the localized title
+        let title = value.translated_title.unwrap_or(value.title);
         let mut db_quest = DbQuestion::new(
             value.frontend_question_id.parse()?,
-            value.title.as_str(),
+            title.as_str(),
             value.title_slug.as_str(),
             value.difficulty,
             value.paid_only,
             value.status,
         );
+        db_quest.ac_rate = value.ac_rate;
+        db_quest.freq_bar = value.freq_bar;
+        db_quest.has_solution = value.has_solution.unwrap_or_default();
+        db_quest.has_video_solution = value.has_video_solution.unwrap_or_default();
+        db_quest.is_favor = value.is_favor.unwrap_or_default();
         if let Some(tts) = value.topic_tags {
             if !tts.is_empty() {
                 for topic in tts {
@@ -108,6 +141,11 @@ impl DbQuestion {
             difficulty,
             paid_only,
             status,
+            ac_rate: None,
+            freq_bar: None,
+            has_solution: false,
+            has_video_solution: false,
+            is_favor: false,
         }
     }
 
@@ -115,7 +153,7 @@ impl DbQuestion {
         self.topics.push(DbTopic::new(slug))
     }
 
-    pub fn mark_accepted<'a>(&mut self) -> DBResult<Option<Vec<Self>>> {
+    pub fn mark_accepted(&mut self) -> DBResult<Option<Vec<Self>>> {
         if self.status.is_none() || self.status == Some("notac".into()) {
             self.status = Some("ac".into());
             return Ok(Some(self.update_in_db()?));
@@ -123,7 +161,7 @@ impl DbQuestion {
         Ok(None)
     }
 
-    pub fn mark_attempted<'a>(&mut self) -> DBResult<Option<Vec<Self>>> {
+    pub fn mark_attempted(&mut self) -> DBResult<Option<Vec<Self>>> {
         if self.status.is_none() {
             self.status = Some("notac".into());
             return Ok(Some(self.update_in_db()?));
@@ -131,7 +169,7 @@ impl DbQuestion {
         Ok(None)
     }
 
-    fn update_in_db<'a>(&self) -> DBResult<Vec<Self>> {
+    fn update_in_db(&self) -> DBResult<Vec<Self>> {
         let rw = get_db_client().rw_transaction()?;
         let old = Self::get_question_by_id(self.id)?;
         if let Some(old_q) = old {
@@ -141,78 +179,40 @@ impl DbQuestion {
         Ok(vec![self.clone()])
     }
 
-    pub fn get_total_questions<'a>() -> DBResult<usize> {
+    pub fn get_total_questions() -> DBResult<usize> {
         let r = get_db_client().r_transaction()?;
         let x = r.scan().primary::<Self>()?;
         Ok(x.all().count())
     }
 
-    pub fn get_question_by_id<'a>(id: u32) -> DBResult<Option<Self>> {
+    /// Every stored question ordered by id, read in a single transaction.
+    pub fn fetch_all() -> DBResult<Vec<Self>> {
         let r = get_db_client().r_transaction()?;
-        let x = r.get().primary::<DbQuestion>(id)?;
-        // x.topics = x.fetch_all_topics(db)?;
-        Ok(x)
-    }
-
-    fn save_all_topics<'a>(&mut self) -> DBResult<()> {
-        save_multiple(&self.topics)
+        let questions = r.scan().primary::<Self>()?.all().collect();
+        Ok(questions)
     }
 
-    pub(crate) fn get_topics(&self) -> &Vec<DbTopic> {
-        &self.topics
-    }
-
-    fn get_topic_question_mapping(&self) -> Vec<TopicQuestionMap> {
-        self.get_topics()
-            .iter()
-            .map(|q| TopicQuestionMap::new(&q.slug, self.id))
-            .collect::<Vec<_>>()
-    }
-
-    fn get_question_topic_mapping(&self) -> Vec<QuestionTopicMap> {
-        self.get_topics()
-            .iter()
-            .map(|q| QuestionTopicMap::new(self.id, &q.slug))
-            .collect::<Vec<_>>()
+    /// Questions in the order of the ids, the ones missing in the db are skipped.
+    pub fn get_questions_by_ids(ids: &[u32]) -> DBResult<Vec<Self>> {
+        let r = get_db_client().r_transaction()?;
+        let mut questions = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(question) = r.get().primary::<Self>(*id)? {
+                questions.push(question);
+            }
+        }
+        Ok(questions)
     }
 
-    pub fn save_multiple_to_db(questions: Vec<Self>) {
-        let topic_question_map = questions
-            .iter()
-            .map(|q| q.get_topic_question_mapping())
-            .flatten()
-            .collect::<Vec<_>>();
-
-        let question_topic_map = questions
-            .iter()
-            .map(|q| q.get_question_topic_mapping())
-            .flatten()
-            .collect::<Vec<_>>();
-
-        let topics = questions
-            .iter()
-            .map(|q| q.get_topics().iter().map(|t| t.clone()))
-            .flatten()
-            .collect::<Vec<_>>();
-
-        save_multiple(&topic_question_map).unwrap();
-        save_multiple(&question_topic_map).unwrap();
-        save_multiple(&topics).unwrap();
-        save_multiple(&questions).unwrap();
+    pub fn get_question_by_id(id: u32) -> DBResult<Option<Self>> {
+        let r = get_db_client().r_transaction()?;
+        let x = r.get().primary::<DbQuestion>(id)?;
+        // x.topics = x.fetch_all_topics(db)?;
+        Ok(x)
     }
 
-    pub fn save_to_db<'a>(&mut self) -> DBResult<bool> {
-        // save Topic -> Question Mapping
-        TopicQuestionMap::save_mapping(self)?;
-
-        // save Question -> Topic Mapping
-        QuestionTopicMap::save_mapping(self)?;
-
-        // save DbTopics for the question
-        self.save_all_topics()?;
-
-        // save question
-        save(self)?;
-        return Ok(true);
+    pub fn save_to_db(&mut self) -> DBResult<bool> {
+        Self::save_questions(vec![self.clone()], false)?;
+        Ok(true)
     }
 }
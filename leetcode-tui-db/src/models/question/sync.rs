@@ -0,0 +1,263 @@
+use super::super::{topic::DbTopic, QuestionTopicMap, QuestionTopicMapKey, TopicQuestionMap};
+use super::DbQuestion;
+use crate::{errors::DBResult, get_db_client};
+use leetcode_tui_shared::date::parse_rfc3339;
+use native_db::transaction::RwTransaction;
+use native_db::*;
+use native_model::{native_model, Model};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const SYNC_STATE_KEY: &str = "progress";
+
+/// Where the last incremental sync stopped reading the progress of the user.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 12, version = 1)]
+#[native_db]
+pub struct DbSyncState {
+    #[primary_key]
+    key: String,
+    /// `lastSubmittedAt` of the most recent submission seen, RFC 3339
+    pub last_submitted_at: Option<String>,
+}
+
+impl DbSyncState {
+    pub fn last_submitted_at() -> DBResult<Option<String>> {
+        let r = get_db_client().r_transaction()?;
+        Ok(r.get()
+            .primary::<Self>(SYNC_STATE_KEY.to_string())?
+            .and_then(|s| s.last_submitted_at))
+    }
+}
+
+/// Status of a question taken from the progress of the user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    pub id: u32,
+    pub status: Option<String>,
+    pub last_submitted_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusChange {
+    pub id: u32,
+    pub title: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Retag {
+    pub id: u32,
+    pub title: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// What a sync changed in the db.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncSummary {
+    /// Ids and titles of the questions stored for the first time
+    pub new: Vec<(u32, String)>,
+    pub status_changes: Vec<StatusChange>,
+    pub retagged: Vec<Retag>,
+    /// Topics no question refers to anymore
+    pub removed_topics: Vec<String>,
+}
+
+impl SyncSummary {
+    pub fn extend(&mut self, other: Self) {
+        self.new.extend(other.new);
+        self.status_changes.extend(other.status_changes);
+        self.retagged.extend(other.retagged);
+        self.removed_topics.extend(other.removed_topics);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.new.is_empty()
+            && self.status_changes.is_empty()
+            && self.retagged.is_empty()
+            && self.removed_topics.is_empty()
+    }
+}
+
+fn topic_slugs(question: &DbQuestion) -> HashSet<&str> {
+    question.topics.iter().map(|t| t.slug.as_str()).collect()
+}
+
+/// The mappings of the question, they are what the topics list. Older dbs may
+/// hold mappings the question itself no longer names.
+fn mapped_topics(rw: &RwTransaction, question_id: u32) -> DBResult<Vec<QuestionTopicMap>> {
+    let mappings = rw
+        .scan()
+        .secondary::<QuestionTopicMap>(QuestionTopicMapKey::question_id)?
+        .start_with(question_id)
+        .filter(|m| m.question_id == question_id)
+        .collect();
+    Ok(mappings)
+}
+
+fn remove_stale_topics(rw: &RwTransaction, summary: &mut SyncSummary) -> DBResult<()> {
+    let referenced = rw
+        .scan()
+        .primary::<DbQuestion>()?
+        .all()
+        .flat_map(|q| q.topics.into_iter().map(|t| t.slug))
+        .collect::<HashSet<_>>();
+    let stale_topics = rw
+        .scan()
+        .primary::<DbTopic>()?
+        .all()
+        .filter(|t| !referenced.contains(&t.slug))
+        .collect::<Vec<_>>();
+    for topic in stale_topics {
+        summary.removed_topics.push(topic.slug.clone());
+        rw.remove(topic)?;
+    }
+    Ok(())
+}
+
+impl DbQuestion {
+    /// Upserts the questions in a single transaction, nothing is written when
+    /// any of it fails. The topic mappings follow the topics of the questions
+    /// and the topics left without questions are removed.
+    pub fn save_multiple_to_db(questions: Vec<Self>) -> DBResult<SyncSummary> {
+        Self::save_questions(questions, true)
+    }
+
+    /// `sweep_topics` removes the topics left without questions, which scans
+    /// every question. A single changed question is saved without it.
+    pub(super) fn save_questions(
+        questions: Vec<Self>,
+        sweep_topics: bool,
+    ) -> DBResult<SyncSummary> {
+        let rw = get_db_client().rw_transaction()?;
+        let mut summary = SyncSummary::default();
+
+        for question in questions {
+            let old = rw.get().primary::<Self>(question.id)?;
+            let mappings = mapped_topics(&rw, question.id)?;
+            let old_topics = mappings
+                .iter()
+                .map(|m| m.topic_id.as_str())
+                .collect::<HashSet<_>>();
+            let new_topics = topic_slugs(&question);
+
+            let mut added = new_topics
+                .difference(&old_topics)
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>();
+            let mut removed = old_topics
+                .difference(&new_topics)
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>();
+            added.sort();
+            removed.sort();
+
+            for mapping in mappings.iter().filter(|m| removed.contains(&m.topic_id)) {
+                let reverse = TopicQuestionMap::new(&mapping.topic_id, question.id);
+                if let Some(reverse) = rw.get().primary::<TopicQuestionMap>(reverse.id)? {
+                    rw.remove(reverse)?;
+                }
+                rw.remove(mapping.clone())?;
+            }
+            for slug in &added {
+                rw.insert(TopicQuestionMap::new(slug, question.id))?;
+                rw.insert(QuestionTopicMap::new(question.id, slug))?;
+                if rw.get().primary::<DbTopic>(slug.clone())?.is_none() {
+                    rw.insert(DbTopic::new(slug))?;
+                }
+            }
+
+            match old {
+                None => {
+                    summary.new.push((question.id, question.title.clone()));
+                    rw.insert(question)?;
+                }
+                Some(old) => {
+                    if old.status != question.status {
+                        summary.status_changes.push(StatusChange {
+                            id: question.id,
+                            title: question.title.clone(),
+                            from: old.status.clone(),
+                            to: question.status.clone(),
+                        });
+                    }
+                    if !added.is_empty() || !removed.is_empty() {
+                        summary.retagged.push(Retag {
+                            id: question.id,
+                            title: question.title.clone(),
+                            added,
+                            removed,
+                        });
+                    }
+                    if old != question {
+                        rw.update(old, question)?;
+                    }
+                }
+            }
+        }
+
+        if sweep_topics {
+            remove_stale_topics(&rw, &mut summary)?;
+        }
+        rw.commit()?;
+        Ok(summary)
+    }
+
+    /// Highest id stored, new questions are the ones above it.
+    pub fn max_id() -> DBResult<Option<u32>> {
+        let r = get_db_client().r_transaction()?;
+        let last = r.scan().primary::<Self>()?.all().next_back();
+        Ok(last.map(|q| q.id))
+    }
+
+    /// Updates the statuses in a single transaction and moves the progress
+    /// cursor to the latest submission. Questions missing in the db are skipped.
+    pub fn apply_progress(progress: &[Progress]) -> DBResult<SyncSummary> {
+        let rw = get_db_client().rw_transaction()?;
+        let mut summary = SyncSummary::default();
+
+        for entry in progress {
+            let Some(old) = rw.get().primary::<Self>(entry.id)? else {
+                continue;
+            };
+            if old.status == entry.status {
+                continue;
+            }
+            summary.status_changes.push(StatusChange {
+                id: old.id,
+                title: old.title.clone(),
+                from: old.status.clone(),
+                to: entry.status.clone(),
+            });
+            let mut new = old.clone();
+            new.status = entry.status.clone();
+            rw.update(old, new)?;
+        }
+
+        let old_cursor = rw
+            .get()
+            .primary::<DbSyncState>(SYNC_STATE_KEY.to_string())?;
+        let latest = progress
+            .iter()
+            .map(|p| p.last_submitted_at.clone())
+            .chain(
+                old_cursor
+                    .as_ref()
+                    .and_then(|s| s.last_submitted_at.clone()),
+            )
+            .max_by_key(|t| parse_rfc3339(t));
+        let new_cursor = DbSyncState {
+            key: SYNC_STATE_KEY.into(),
+            last_submitted_at: latest,
+        };
+        match old_cursor {
+            Some(old) => rw.update(old, new_cursor)?,
+            None => rw.insert(new_cursor)?,
+        }
+
+        rw.commit()?;
+        Ok(summary)
+    }
+}
@@ -0,0 +1,30 @@
+//! The question as stored before the problemset extras were kept around.
+use super::super::{topic::DbTopic, *};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 1, version = 1)]
+#[native_db]
+pub struct DbQuestion {
+    #[primary_key]
+    pub id: u32,
+    pub title: String,
+    pub title_slug: String,
+    pub difficulty: String,
+    pub paid_only: bool,
+    pub status: Option<String>,
+    pub topics: Vec<DbTopic>,
+}
+
+impl From<super::DbQuestion> for DbQuestion {
+    fn from(value: super::DbQuestion) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            title_slug: value.title_slug,
+            difficulty: value.difficulty,
+            paid_only: value.paid_only,
+            status: value.status,
+            topics: value.topics,
+        }
+    }
+}
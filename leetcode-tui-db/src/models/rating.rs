@@ -0,0 +1,116 @@
+use super::*;
+use crate::{
+    api::types::{
+        like_ratio,
+        question_detail::{QuestionDetail, QuestionStats},
+        question_votes::QuestionVotes,
+    },
+    errors::{DBResult, DbErr},
+    get_db_client, save, save_multiple, DbQuestion,
+};
+use std::collections::HashMap;
+
+/// Votes and acceptance of a question. The problemset list does not carry
+/// them, they are stored whenever the detail of the question is fetched and
+/// fetched in batches for the like ratio sort.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 6, version = 1)]
+#[native_db]
+pub struct DbQuestionRating {
+    #[primary_key]
+    pub id: u32,
+    pub likes: u32,
+    pub dislikes: u32,
+    pub total_accepted: u64,
+    pub total_submission: u64,
+}
+
+impl DbQuestionRating {
+    fn new(
+        frontend_id: &str,
+        likes: u32,
+        dislikes: u32,
+        stats: Option<&QuestionStats>,
+    ) -> DBResult<Self> {
+        let (total_accepted, total_submission) = stats
+            .map(|s| (s.total_accepted_raw, s.total_submission_raw))
+            .unwrap_or_default();
+        Ok(Self {
+            id: frontend_id.parse()?,
+            likes,
+            dislikes,
+            total_accepted,
+            total_submission,
+        })
+    }
+}
+
+impl TryFrom<&QuestionDetail> for DbQuestionRating {
+    type Error = DbErr;
+
+    fn try_from(value: &QuestionDetail) -> Result<Self, Self::Error> {
+        Self::new(
+            &value.question_frontend_id,
+            value.likes,
+            value.dislikes,
+            value.stats.as_ref(),
+        )
+    }
+}
+
+impl TryFrom<&QuestionVotes> for DbQuestionRating {
+    type Error = DbErr;
+
+    fn try_from(value: &QuestionVotes) -> Result<Self, Self::Error> {
+        Self::new(
+            &value.question_frontend_id,
+            value.likes,
+            value.dislikes,
+            value.stats.as_ref(),
+        )
+    }
+}
+
+impl DbQuestionRating {
+    /// `None` when nobody voted yet.
+    pub fn like_ratio(&self) -> Option<f64> {
+        like_ratio(self.likes, self.dislikes)
+    }
+
+    pub fn save_to_db(&self) -> DBResult<()> {
+        save(self)
+    }
+
+    pub fn save_all(ratings: &Vec<Self>) -> DBResult<()> {
+        save_multiple(ratings)
+    }
+
+    pub fn get_rating_by_id(id: u32) -> DBResult<Option<Self>> {
+        let r = get_db_client().r_transaction()?;
+        Ok(r.get().primary::<Self>(id)?)
+    }
+
+    /// Slugs of the questions no rating was stored for yet.
+    pub fn unrated_slugs() -> DBResult<Vec<String>> {
+        let r = get_db_client().r_transaction()?;
+        let mut slugs = vec![];
+        for question in r.scan().primary::<DbQuestion>()?.all() {
+            if r.get().primary::<Self>(question.id)?.is_none() {
+                slugs.push(question.title_slug);
+            }
+        }
+        Ok(slugs)
+    }
+
+    /// Like ratio of every question rated so far that has votes, keyed by question id.
+    pub fn fetch_like_ratios() -> DBResult<HashMap<u32, f64>> {
+        let r = get_db_client().r_transaction()?;
+        let ratios = r
+            .scan()
+            .primary::<Self>()?
+            .all()
+            .filter_map(|rating| Some((rating.id, rating.like_ratio()?)))
+            .collect();
+        Ok(ratios)
+    }
+}
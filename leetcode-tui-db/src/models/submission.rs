@@ -0,0 +1,133 @@
+use super::*;
+use crate::{
+    api::types::{submission_details::SubmissionDetails, submission_list::Submission},
+    errors::DBResult,
+    get_db_client,
+};
+use leetcode_tui_shared::date::format_timestamp;
+use std::fmt::Display;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct DbSubmissionDetails {
+    pub code: String,
+    pub runtime_percentile: Option<f64>,
+    pub memory_percentile: Option<f64>,
+    pub runtime_distribution: Vec<(String, f64)>,
+    pub memory_distribution: Vec<(String, f64)>,
+}
+
+impl From<SubmissionDetails> for DbSubmissionDetails {
+    fn from(value: SubmissionDetails) -> Self {
+        Self {
+            code: value.code,
+            runtime_percentile: value.runtime_percentile,
+            memory_percentile: value.memory_percentile,
+            runtime_distribution: value
+                .runtime_distribution
+                .map(|d| d.distribution)
+                .unwrap_or_default(),
+            memory_distribution: value
+                .memory_distribution
+                .map(|d| d.distribution)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 5, version = 1)]
+#[native_db]
+pub struct DbSubmission {
+    #[primary_key]
+    pub id: u64,
+    #[secondary_key]
+    pub question_id: u32,
+    pub status: String,
+    pub lang: String,
+    pub runtime: String,
+    pub memory: String,
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+    /// Fetched lazily, only when the submission is opened
+    pub details: Option<DbSubmissionDetails>,
+}
+
+impl Display for DbSubmission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}  {: <22}{: <12}{: <10}{}",
+            format_timestamp(self.timestamp),
+            self.status,
+            self.lang,
+            self.runtime,
+            self.memory
+        )
+    }
+}
+
+impl DbSubmission {
+    pub fn new(question_id: u32, submission: Submission) -> Self {
+        Self {
+            id: submission.id,
+            question_id,
+            status: submission.status_display,
+            lang: submission.lang,
+            runtime: submission.runtime,
+            memory: submission.memory,
+            timestamp: submission.timestamp,
+            details: None,
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.status == "Accepted"
+    }
+
+    /// Latest submissions come first.
+    pub fn fetch_by_question(question_id: u32) -> DBResult<Vec<Self>> {
+        let r = get_db_client().r_transaction()?;
+        let mut submissions: Vec<Self> = r
+            .scan()
+            .secondary::<Self>(DbSubmissionKey::question_id)?
+            .start_with(question_id)
+            .collect();
+        submissions.sort_by_key(|s| std::cmp::Reverse(s.timestamp));
+        Ok(submissions)
+    }
+
+    pub fn get_submission_by_id(id: u64) -> DBResult<Option<Self>> {
+        let r = get_db_client().r_transaction()?;
+        Ok(r.get().primary::<Self>(id)?)
+    }
+
+    /// Upserts the submissions keeping the details that were already fetched.
+    pub fn save_multiple_to_db(submissions: Vec<Self>) -> DBResult<()> {
+        let rw = get_db_client().rw_transaction()?;
+        for mut submission in submissions {
+            if let Some(existing) = rw.get().primary::<Self>(submission.id)? {
+                if submission.details.is_none() {
+                    submission.details = existing.details.clone();
+                }
+                rw.update(existing, submission)?;
+            } else {
+                rw.insert(submission)?;
+            }
+        }
+        rw.commit()?;
+        Ok(())
+    }
+
+    pub fn set_details(&mut self, details: DbSubmissionDetails) -> DBResult<()> {
+        let rw = get_db_client().rw_transaction()?;
+        let old = self.clone();
+        self.details = Some(details);
+        if rw.get().primary::<Self>(self.id)?.is_some() {
+            rw.update(old, self.clone())?;
+        } else {
+            rw.insert(self.clone())?;
+        }
+        rw.commit()?;
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
 use crate::{errors::DBResult, get_db_client};
 
-use super::{question::DbQuestion, *};
+use super::{
+    favorite::{Favorites, FAVORITES_TOPIC},
+    list::LIST_TOPIC_PREFIX,
+    question::DbQuestion,
+    *,
+};
 
 #[native_model(id = 2, version = 1)]
 #[native_db]
@@ -21,32 +26,43 @@ impl DbTopic {
         Self { slug: slug.into() }
     }
 
-    pub fn fetch_all<'a>() -> DBResult<Vec<DbTopic>> {
+    pub fn fetch_all() -> DBResult<Vec<DbTopic>> {
         let r = get_db_client().r_transaction()?;
-        let x = r.scan().primary::<Self>()?.all().into_iter().collect();
+        let x = r.scan().primary::<Self>()?.all().collect();
         Ok(x)
     }
 
-    pub fn fetch_questions<'a>(&self) -> DBResult<Vec<DbQuestion>> {
-        let q_ids = if self.slug.eq("all") {
-            (1..=5000).map(|x| x as u32).collect()
-        } else {
-            TopicQuestionMap::get_all_question_by_topic(self)?
-        };
-        let mut v = vec![];
-        for q_id in q_ids {
-            if let Some(available_ques) = DbQuestion::get_question_by_id(q_id)? {
-                v.push(available_ques);
-            };
+    /// Id of the [`DbList`] when the topic stands for a list.
+    pub fn list_id(&self) -> Option<&str> {
+        self.slug.strip_prefix(LIST_TOPIC_PREFIX)
+    }
+
+    /// Ids of the questions under the topic, `None` for "all".
+    pub fn question_ids(&self) -> DBResult<Option<Vec<u32>>> {
+        if self.slug == "all" {
+            return Ok(None);
+        }
+        if let Some(list_id) = self.list_id() {
+            return Ok(Some(ListQuestionMap::get_all_question_by_list(list_id)?));
+        }
+        if self.slug == FAVORITES_TOPIC {
+            return Ok(Some(Favorites::fetch_ids_sorted()?));
+        }
+        Ok(Some(TopicQuestionMap::get_all_question_by_topic(self)?))
+    }
+
+    pub fn fetch_questions(&self) -> DBResult<Vec<DbQuestion>> {
+        match self.question_ids()? {
+            Some(ids) => DbQuestion::get_questions_by_ids(&ids),
+            None => DbQuestion::fetch_all(),
         }
-        Ok(v)
     }
 
-    pub fn get_topic_by_slug<'a>(slug: &str) -> DBResult<Self> {
+    pub fn get_topic_by_slug(slug: &str) -> DBResult<Self> {
         let r = get_db_client().r_transaction()?;
 
-        Ok(r.get()
+        r.get()
             .primary(slug.to_string())?
-            .ok_or(crate::errors::DbErr::TopicsNotFoundInDb(slug.to_string()))?)
+            .ok_or(crate::errors::DbErr::TopicsNotFoundInDb(slug.to_string()))
     }
 }
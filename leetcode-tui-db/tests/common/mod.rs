@@ -0,0 +1,47 @@
+//! Question fixtures shared by the db tests, not every test uses all of them.
+#![allow(dead_code)]
+use leetcode_core::types::problemset_question_list::Question;
+use leetcode_tui_db::DbQuestion;
+
+/// Question as the problemset list sends it, titled and slugged after its id.
+pub fn question(id: u32, status: Option<&str>, topics: &[&str]) -> DbQuestion {
+    let tags = topics
+        .iter()
+        .map(|slug| format!(r#"{{"name": "{slug}", "id": "{slug}", "slug": "{slug}"}}"#))
+        .collect::<Vec<_>>()
+        .join(",");
+    let status = status.map_or("null".into(), |s| format!(r#""{s}""#));
+    let json = format!(
+        r#"{{"acRate": 50.0, "difficulty": "Easy", "freqBar": null, "frontendQuestionId": "{id}",
+            "isFavor": false, "paidOnly": false, "status": {status}, "title": "Question {id}",
+            "titleSlug": "question-{id}", "topicTags": [{tags}], "hasSolution": false,
+            "hasVideoSolution": false}}"#
+    );
+    serde_json::from_str::<Question>(&json)
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+/// Saves a question without topics, titled and slugged after its id.
+pub fn save_question(id: u32, status: Option<&str>) {
+    save_question_as(
+        id,
+        &format!("Question {id}"),
+        &format!("question-{id}"),
+        status,
+    );
+}
+
+pub fn save_question_as(id: u32, title: &str, slug: &str, status: Option<&str>) {
+    DbQuestion::new(
+        id,
+        title,
+        slug,
+        "Easy".into(),
+        false,
+        status.map(String::from),
+    )
+    .save_to_db()
+    .unwrap();
+}
@@ -0,0 +1,73 @@
+use leetcode_core::types::daily_challenge_calendar;
+use leetcode_tui_db::DbDailyChallenge;
+use leetcode_tui_shared::date::{days_in_month, weekday};
+
+fn calendar_json(days: &[(&str, &str)]) -> String {
+    let challenges = days
+        .iter()
+        .enumerate()
+        .map(|(i, (date, status))| {
+            format!(
+                r#"{{"date": "{date}", "userStatus": "{status}", "link": "", "question": {{"questionFrontendId": "{}", "title": "Question {}", "titleSlug": "question-{}"}}}}"#,
+                i + 1,
+                i + 1,
+                i + 1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(r#"{{"data": {{"dailyCodingChallengeV2": {{"challenges": [{challenges}]}}}}}}"#)
+}
+
+fn save_calendar(days: &[(&str, &str)]) {
+    let data: daily_challenge_calendar::Data = serde_json::from_str(&calendar_json(days)).unwrap();
+    let challenges = data
+        .data
+        .challenges
+        .iter()
+        .map(|c| c.try_into().unwrap())
+        .collect::<Vec<DbDailyChallenge>>();
+    DbDailyChallenge::save_multiple_to_db(&challenges).unwrap();
+}
+
+#[test]
+fn test_daily_challenge_history_and_streak() {
+    leetcode_tui_db::init(None).unwrap();
+    save_calendar(&[
+        ("2024-02-28", "Finish"),
+        ("2024-02-29", "Finish"),
+        ("2024-03-01", "NotStart"),
+        ("2024-03-02", "Finish"),
+        ("2024-03-03", "Finish"),
+        ("2024-03-04", "NotStart"),
+    ]);
+
+    let march = DbDailyChallenge::fetch_month(2024, 3).unwrap();
+    assert_eq!(march.len(), 4);
+    assert_eq!(march[0].date, "2024-03-01");
+    assert!(!march[0].completed);
+
+    // unsolved today keeps yesterday's streak alive
+    assert_eq!(DbDailyChallenge::current_streak((2024, 3, 4)).unwrap(), 2);
+    assert_eq!(DbDailyChallenge::current_streak((2024, 3, 5)).unwrap(), 0);
+    assert_eq!(DbDailyChallenge::current_streak((2024, 2, 29)).unwrap(), 2);
+
+    // solving a missed day later upserts the stored one
+    save_calendar(&[("2024-03-04", "Finish")]);
+    assert_eq!(DbDailyChallenge::current_streak((2024, 3, 4)).unwrap(), 3);
+    assert!(
+        DbDailyChallenge::get_by_date("2024-03-04")
+            .unwrap()
+            .unwrap()
+            .completed
+    );
+}
+
+#[test]
+fn test_calendar_helpers() {
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2023, 2), 28);
+    assert_eq!(days_in_month(2024, 12), 31);
+    // 2024-03-01 was a Friday
+    assert_eq!(weekday(2024, 3, 1), 4);
+}
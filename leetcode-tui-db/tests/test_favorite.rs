@@ -0,0 +1,33 @@
+mod common;
+
+use common::save_question;
+use leetcode_tui_db::{DbQuestion, Favorites};
+
+#[test]
+fn test_favorites_are_toggled_and_listed_as_a_topic() {
+    leetcode_tui_db::init(None).unwrap();
+    for id in 1..=4 {
+        save_question(id, None);
+    }
+
+    Favorites::set_favorite(3, true).unwrap();
+    Favorites::set_favorite(1, true).unwrap();
+    Favorites::set_favorite(4, true).unwrap();
+    Favorites::set_favorite(3, false).unwrap();
+    // removing a question which is no favorite is fine, so is adding one twice
+    Favorites::set_favorite(2, false).unwrap();
+    Favorites::set_favorite(4, true).unwrap();
+    assert!(Favorites::set_favorite(4000, true).is_err());
+
+    assert!(Favorites::is_favorite(4).unwrap());
+    assert!(!Favorites::is_favorite(3).unwrap());
+    assert!(!Favorites::is_favorite(4000).unwrap());
+    assert!(DbQuestion::get_question_by_id(4).unwrap().unwrap().is_favor);
+    let ids = Favorites::topic()
+        .fetch_questions()
+        .unwrap()
+        .iter()
+        .map(|q| q.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [1, 4]);
+}
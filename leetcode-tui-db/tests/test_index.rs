@@ -0,0 +1,62 @@
+mod common;
+
+use common::question;
+use leetcode_tui_db::{DbQuestion, DbTopic, Favorites, QuestionIndex};
+
+fn ids(questions: &[impl AsRef<DbQuestion>]) -> Vec<u32> {
+    questions.iter().map(|q| q.as_ref().id).collect()
+}
+
+#[test]
+fn test_index_covers_every_question_and_matches_topics_exactly() {
+    leetcode_tui_db::init(None).unwrap();
+    DbQuestion::save_multiple_to_db(vec![
+        question(1, None, &["string"]),
+        question(5001, None, &["string-matching"]),
+        question(6000, None, &["string", "string-matching"]),
+    ])
+    .unwrap();
+    Favorites::set_favorite(5001, true).unwrap();
+
+    let string = DbTopic::new("string");
+    let all = DbTopic::new("all");
+
+    // read straight from the db
+    let from_db = all.fetch_questions().unwrap();
+    assert_eq!(
+        from_db.iter().map(|q| q.id).collect::<Vec<_>>(),
+        [1, 5001, 6000]
+    );
+    let from_db = string.fetch_questions().unwrap();
+    assert_eq!(from_db.iter().map(|q| q.id).collect::<Vec<_>>(), [1, 6000]);
+
+    let mut index = QuestionIndex::build().unwrap();
+    assert_eq!(index.len(), 3);
+    assert_eq!(ids(&index.questions(&all).unwrap()), [1, 5001, 6000]);
+    assert_eq!(ids(&index.questions(&string).unwrap()), [1, 6000]);
+    assert_eq!(
+        ids(&index.questions(&DbTopic::new("string-matching")).unwrap()),
+        [5001, 6000]
+    );
+    assert_eq!(ids(&index.questions(&Favorites::topic()).unwrap()), [5001]);
+    assert!(index.questions(&DbTopic::new("graph")).unwrap().is_empty());
+
+    // a retagged question moves between the topics
+    index.insert(question(1, None, &["array"]));
+    assert_eq!(ids(&index.questions(&string).unwrap()), [6000]);
+    assert_eq!(ids(&index.questions(&DbTopic::new("array")).unwrap()), [1]);
+
+    // so does a question added to or removed from the favorites
+    let mut favorite = question(1, None, &["array"]);
+    favorite.is_favor = true;
+    index.insert(favorite);
+    index.insert(question(5001, None, &["string-matching"]));
+    assert_eq!(ids(&index.questions(&Favorites::topic()).unwrap()), [1]);
+    assert_eq!(index.favorites_count(), 1);
+
+    // progress of a list, unknown ids are skipped
+    let mut accepted = question(6000, None, &["string"]);
+    accepted.status = Some("ac".into());
+    index.insert(accepted);
+    assert_eq!(index.progress(&[6000, 1, 4000]), (1, 2));
+}
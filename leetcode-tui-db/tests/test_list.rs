@@ -0,0 +1,41 @@
+mod common;
+
+use common::save_question;
+use leetcode_tui_db::models::list::ListKind;
+use leetcode_tui_db::DbList;
+
+#[test]
+fn test_lists_keep_their_order_and_progress() {
+    leetcode_tui_db::init(None).unwrap();
+    save_question(1, Some("ac"));
+    save_question(2, None);
+    save_question(3, Some("notac"));
+
+    let plan = DbList::new("leetcode-75", "LeetCode 75", ListKind::StudyPlan);
+    plan.save_with_questions(&[3, 1, 2]).unwrap();
+    // shares the prefix of the plan id, must not leak into it
+    let other = DbList::new("leetcode-75-ii", "Another", ListKind::Favorite);
+    other.save_with_questions(&[2]).unwrap();
+
+    let ids = plan
+        .fetch_questions()
+        .unwrap()
+        .iter()
+        .map(|q| q.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [3, 1, 2]);
+    assert_eq!(plan.progress().unwrap(), (1, 3));
+
+    // resaving replaces the membership, unknown questions are skipped
+    plan.save_with_questions(&[2, 4000]).unwrap();
+    let topic = plan.as_topic();
+    assert_eq!(topic.list_id(), Some("leetcode-75"));
+    let ids = topic
+        .fetch_questions()
+        .unwrap()
+        .iter()
+        .map(|q| q.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [2]);
+    assert_eq!(DbList::fetch_all().unwrap().len(), 2);
+}
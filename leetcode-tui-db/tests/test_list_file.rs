@@ -0,0 +1,70 @@
+mod common;
+
+use common::save_question_as;
+use leetcode_tui_db::errors::DbErr;
+use leetcode_tui_db::models::list::ListKind;
+use leetcode_tui_db::DbList;
+use std::path::PathBuf;
+
+fn temp_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("leetcode-tui-lists-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn question_ids(list: &DbList) -> Vec<u32> {
+    list.fetch_questions()
+        .unwrap()
+        .iter()
+        .map(|q| q.id)
+        .collect()
+}
+
+#[test]
+fn test_import_and_export_list_files() {
+    leetcode_tui_db::init(None).unwrap();
+    save_question_as(1, "Title, 1", "two-sum", None);
+    save_question_as(121, "Title, 121", "best-time-to-buy-and-sell-stock", None);
+    save_question_as(3, "Title, 3", "longest-substring", None);
+    let dir = temp_dir();
+
+    let csv = dir.join("grind.csv");
+    std::fs::write(&csv, "id\n121\ntwo-sum\n9999\nno-such-slug\n121\n").unwrap();
+    let report = DbList::import(&csv, None).unwrap();
+    assert_eq!(report.list.name, "grind");
+    assert_eq!(report.list.kind, ListKind::Custom);
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.unknown, ["9999", "no-such-slug"]);
+    assert_eq!(question_ids(&report.list), [121, 1]);
+
+    let toml_file = dir.join("blind.toml");
+    std::fs::write(
+        &toml_file,
+        "name = \"Blind 75\"\nquestions = [\"longest-substring\", 1]\n",
+    )
+    .unwrap();
+    let report = DbList::import(&toml_file, None).unwrap();
+    assert_eq!(report.list.name, "Blind 75");
+    assert!(report.unknown.is_empty());
+    assert_eq!(question_ids(&report.list), [3, 1]);
+
+    // exported files import back into the same list
+    for file in ["export.csv", "export.toml"] {
+        let path = dir.join(file);
+        report.list.export(&path).unwrap();
+        let reimported = DbList::import(&path, Some("Blind 75")).unwrap();
+        assert_eq!(reimported.list.id, report.list.id);
+        assert_eq!(question_ids(&reimported.list), [3, 1]);
+    }
+    assert!(std::fs::read_to_string(dir.join("export.csv"))
+        .unwrap()
+        .contains("3,longest-substring,\"Title, 3\""));
+
+    let txt = dir.join("list.txt");
+    std::fs::write(&txt, "two-sum\n").unwrap();
+    assert!(matches!(
+        DbList::import(&txt, None),
+        Err(DbErr::ListFileFormatError(_))
+    ));
+    std::fs::remove_dir_all(dir).ok();
+}
@@ -0,0 +1,124 @@
+use leetcode_tui_db::migration::{self, backup_path, current_version, stored_version};
+use leetcode_tui_db::models::question::v1;
+use leetcode_tui_db::{DbQuestion, DbTopic};
+use native_db::*;
+use native_model::{native_model, Model};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Same layout as the version record of the crate, to fake a newer release.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[native_model(id = 11, version = 1)]
+#[native_db]
+struct SchemaVersion {
+    #[primary_key]
+    key: String,
+    version: u32,
+}
+
+fn v1_question(id: u32) -> v1::DbQuestion {
+    v1::DbQuestion {
+        id,
+        title: format!("Question {id}"),
+        title_slug: format!("question-{id}"),
+        difficulty: "Easy".into(),
+        paid_only: false,
+        status: Some("ac".into()),
+        topics: vec![DbTopic::new("array")],
+    }
+}
+
+fn temp_db(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("leetcode-tui-{name}-{}.db", std::process::id()))
+}
+
+#[test]
+fn test_v1_questions_are_upgraded_in_place() {
+    let path = temp_db("migration");
+    {
+        // a db written before the schema version was recorded
+        let mut builder = DatabaseBuilder::new();
+        builder.define::<v1::DbQuestion>().unwrap();
+        builder.define::<DbTopic>().unwrap();
+        let db = builder.create(&path).unwrap();
+        let rw = db.rw_transaction().unwrap();
+        for id in 1..=3 {
+            rw.insert(v1_question(id)).unwrap();
+        }
+        rw.insert(DbTopic::new("array")).unwrap();
+        rw.commit().unwrap();
+    }
+    let before = std::fs::read(&path).unwrap();
+
+    let mut steps = vec![];
+    leetcode_tui_db::init_with_progress(Some(&path), |progress| {
+        steps.push((progress.step, progress.total, progress.migration.version))
+    })
+    .unwrap();
+    assert_eq!(steps, [(1, 1, 1)]);
+    // taken before the file was opened
+    assert_eq!(std::fs::read(backup_path(&path, 0)).unwrap(), before);
+
+    assert_eq!(DbQuestion::get_total_questions().unwrap(), 3);
+    let question = DbQuestion::get_question_by_id(2).unwrap().unwrap();
+    assert_eq!(question.title, "Question 2");
+    assert_eq!(question.status.as_deref(), Some("ac"));
+    assert_eq!(question.topics, [DbTopic::new("array")]);
+    assert_eq!(question.ac_rate, None);
+    assert!(!question.is_favor);
+    assert_eq!(DbTopic::fetch_all().unwrap().len(), 1);
+
+    std::fs::remove_file(backup_path(&path, 0)).ok();
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_new_db_is_stamped_with_the_current_version() {
+    let mut builder = DatabaseBuilder::new();
+    leetcode_tui_db::define_schema(&mut builder).unwrap();
+    let db = builder.create_in_memory().unwrap();
+
+    let mut ran = 0;
+    migration::run(&db, true, |_| ran += 1).unwrap();
+    assert_eq!(ran, 0);
+    assert_eq!(stored_version(&db).unwrap(), Some(current_version()));
+
+    // nothing pending once stamped
+    migration::run(&db, false, |_| ran += 1).unwrap();
+    assert_eq!(ran, 0);
+}
+
+#[test]
+fn test_db_of_a_newer_release_is_an_error() {
+    let path = temp_db("newer");
+    {
+        let mut builder = DatabaseBuilder::new();
+        builder.define::<SchemaVersion>().unwrap();
+        let db = builder.create(&path).unwrap();
+        let rw = db.rw_transaction().unwrap();
+        rw.insert(SchemaVersion {
+            key: "schema".into(),
+            version: current_version() + 1,
+        })
+        .unwrap();
+        rw.commit().unwrap();
+    }
+
+    let mut builder = DatabaseBuilder::new();
+    leetcode_tui_db::define_schema(&mut builder).unwrap();
+    let result = migration::open(&builder, &path, |_| panic!("nothing to migrate"));
+    assert!(result
+        .err()
+        .unwrap()
+        .to_string()
+        .contains("this release supports up to"));
+    // no backup is left behind
+    let dir = std::fs::read_dir(path.parent().unwrap()).unwrap();
+    let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+    assert!(!dir.filter_map(Result::ok).any(|e| e
+        .file_name()
+        .to_string_lossy()
+        .starts_with(&format!("{file_name}."))));
+
+    std::fs::remove_file(path).ok();
+}
@@ -0,0 +1,70 @@
+use leetcode_tui_db::models::question::sync::{DbSyncState, Progress, StatusChange};
+use leetcode_tui_db::DbQuestion;
+
+fn progress(id: u32, status: &str, last_submitted_at: &str) -> Progress {
+    Progress {
+        id,
+        status: Some(status.into()),
+        last_submitted_at: last_submitted_at.into(),
+    }
+}
+
+#[test]
+fn test_progress_updates_statuses_and_moves_the_cursor() {
+    leetcode_tui_db::init(None).unwrap();
+    assert_eq!(DbQuestion::max_id().unwrap(), None);
+    assert_eq!(DbSyncState::last_submitted_at().unwrap(), None);
+
+    DbQuestion::save_multiple_to_db(vec![
+        DbQuestion::new(1, "Two Sum", "two-sum", "Easy".into(), false, None),
+        DbQuestion::new(42, "Trapping", "trapping", "Hard".into(), false, None),
+        DbQuestion::new(7, "Reverse", "reverse", "Medium".into(), false, None),
+    ])
+    .unwrap();
+    assert_eq!(DbQuestion::max_id().unwrap(), Some(42));
+
+    let summary = DbQuestion::apply_progress(&[
+        progress(42, "notac", "2024-05-02T10:00:00+00:00"),
+        progress(1, "ac", "2024-05-01T09:30:00+00:00"),
+        // not synced yet
+        progress(3000, "ac", "2024-04-01T00:00:00+00:00"),
+    ])
+    .unwrap();
+    assert_eq!(
+        summary.status_changes,
+        vec![
+            StatusChange {
+                id: 42,
+                title: "Trapping".into(),
+                from: None,
+                to: Some("notac".into()),
+            },
+            StatusChange {
+                id: 1,
+                title: "Two Sum".into(),
+                from: None,
+                to: Some("ac".into()),
+            },
+        ]
+    );
+    assert_eq!(
+        DbSyncState::last_submitted_at().unwrap().as_deref(),
+        Some("2024-05-02T10:00:00+00:00")
+    );
+
+    // reading the submission at the cursor again changes nothing
+    let summary =
+        DbQuestion::apply_progress(&[progress(42, "notac", "2024-05-02T10:00:00+00:00")]).unwrap();
+    assert!(summary.is_empty());
+
+    let summary = DbQuestion::apply_progress(&[]).unwrap();
+    assert!(summary.is_empty());
+    assert_eq!(
+        DbSyncState::last_submitted_at().unwrap().as_deref(),
+        Some("2024-05-02T10:00:00+00:00")
+    );
+    assert_eq!(
+        DbQuestion::get_question_by_id(1).unwrap().unwrap().status,
+        Some("ac".into())
+    );
+}
@@ -1,7 +1,7 @@
 use leetcode_core::types::problemset_question_list::Root;
 use leetcode_tui_db::models::question::DbQuestion;
 
-static JSON: &'static str = r#"{
+static JSON: &str = r#"{
             "data": {
                 "problemsetQuestionList": {
                     "total": 2777,
@@ -51,7 +51,7 @@ static JSON: &'static str = r#"{
             }
         }"#;
 
-fn populate_db<'a>() {
+fn populate_db() {
     let root: Root = serde_json::from_str(JSON).unwrap();
     let mut questions = root.get_questions();
     let mut db_quest1 = DbQuestion::try_from(questions.pop().unwrap()).unwrap();
@@ -62,7 +62,7 @@ fn populate_db<'a>() {
 
 #[test]
 fn test_it_should_return_the_number_of_questions_correctly() {
-    leetcode_tui_db::init(None);
+    leetcode_tui_db::init(None).unwrap();
     populate_db();
 
     let result = DbQuestion::get_total_questions().unwrap();
@@ -71,39 +71,37 @@ fn test_it_should_return_the_number_of_questions_correctly() {
 
 #[test]
 fn test_it_should_mark_the_question_accepted_correctly() {
-    leetcode_tui_db::init(None);
+    leetcode_tui_db::init(None).unwrap();
     populate_db();
-    let mut x = DbQuestion::get_question_by_id(1).unwrap();
+    let mut x = DbQuestion::get_question_by_id(1).unwrap().unwrap();
     x.mark_accepted().unwrap();
 
-    let x = DbQuestion::get_question_by_id(1).unwrap();
+    let x = DbQuestion::get_question_by_id(1).unwrap().unwrap();
     assert_eq!(x.status, Some("ac".into()));
 }
 
 #[test]
 fn test_it_should_mark_the_question_attempted_correctly() {
-    leetcode_tui_db::init(None);
+    leetcode_tui_db::init(None).unwrap();
     populate_db();
-    let mut x = DbQuestion::get_question_by_id(2).unwrap();
+    let mut x = DbQuestion::get_question_by_id(2).unwrap().unwrap();
 
     assert_eq!(x.status, None);
 
     x.mark_attempted().unwrap();
 
-    let x = DbQuestion::get_question_by_id(2).unwrap();
+    let x = DbQuestion::get_question_by_id(2).unwrap().unwrap();
     assert_eq!(x.status, Some("notac".into()));
 }
 
 #[test]
-fn test_it_should_add_a_new_question_to_db() {
-    leetcode_tui_db::init(None);
+fn test_it_should_keep_the_problemset_extras() {
+    leetcode_tui_db::init(None).unwrap();
     populate_db();
-    let mut x = DbQuestion::new(5, "helloworld", "helloworld", "medium".into(), true, None);
-    x.save_to_db().unwrap();
-
-    assert_eq!(DbQuestion::get_total_questions().unwrap(), 3);
-
-    let x = DbQuestion::get_question_by_id(5).unwrap();
-    assert_eq!(x.id, 5);
-    assert_eq!(x.title, "helloworld");
+    let question = DbQuestion::get_question_by_id(1).unwrap().unwrap();
+    assert_eq!(question.ac_rate, Some(45.35065222510613));
+    assert_eq!(question.freq_bar, None);
+    assert!(question.has_solution);
+    assert!(!question.has_video_solution);
+    assert!(!question.is_favor);
 }
@@ -0,0 +1,82 @@
+mod common;
+
+use common::save_question;
+use leetcode_core::types::{question_detail, question_votes};
+use leetcode_tui_db::DbQuestionRating;
+
+fn detail_json(id: u32, likes: u32, dislikes: u32) -> String {
+    format!(
+        r#"{{
+            "data": {{
+                "question": {{
+                    "questionId": "{id}",
+                    "questionFrontendId": "{id}",
+                    "title": "Question {id}",
+                    "titleSlug": "question-{id}",
+                    "content": "",
+                    "difficulty": "Easy",
+                    "isPaidOnly": false,
+                    "likes": {likes},
+                    "dislikes": {dislikes},
+                    "hints": [],
+                    "similarQuestions": "[]",
+                    "stats": "{{\"totalAccepted\": \"10\", \"totalSubmission\": \"20\", \"totalAcceptedRaw\": 10, \"totalSubmissionRaw\": 20, \"acRate\": \"50.0%\"}}",
+                    "sampleTestCase": null,
+                    "metaData": null,
+                    "companyTagStats": null
+                }}
+            }}
+        }}"#
+    )
+}
+
+fn save_rating(id: u32, likes: u32, dislikes: u32) {
+    let data: question_detail::Data =
+        serde_json::from_str(&detail_json(id, likes, dislikes)).unwrap();
+    let rating: DbQuestionRating = (&data.data.question).try_into().unwrap();
+    rating.save_to_db().unwrap();
+}
+
+#[test]
+fn test_ratings_are_upserted_and_ratios_fetched() {
+    leetcode_tui_db::init(None).unwrap();
+    save_rating(1, 10, 10);
+    save_rating(2, 0, 0);
+    // votes changed since the last fetch
+    save_rating(1, 30, 10);
+
+    let rating = DbQuestionRating::get_rating_by_id(1).unwrap().unwrap();
+    assert_eq!(rating.total_submission, 20);
+
+    let ratios = DbQuestionRating::fetch_like_ratios().unwrap();
+    // nobody voted on 2, its ratio is unknown
+    assert_eq!(ratios.len(), 1);
+    assert_eq!(ratios[&1], 0.75);
+}
+
+#[test]
+fn test_votes_fill_in_the_unrated_questions() {
+    leetcode_tui_db::init(None).unwrap();
+    for id in [101, 102, 103] {
+        save_question(id, None);
+    }
+    let votes: question_votes::Data = serde_json::from_str(
+        r#"{"data": {
+            "q0": {"questionFrontendId": "101", "likes": 0, "dislikes": 0, "stats": null},
+            "q1": {"questionFrontendId": "103", "likes": 0, "dislikes": 0, "stats": null},
+            "q2": null
+        }}"#,
+    )
+    .unwrap();
+    let ratings = votes
+        .into_votes()
+        .iter()
+        .map(|v| v.try_into().unwrap())
+        .collect();
+    DbQuestionRating::save_all(&ratings).unwrap();
+
+    let unrated = DbQuestionRating::unrated_slugs().unwrap();
+    assert!(unrated.contains(&"question-102".to_string()));
+    assert!(!unrated.contains(&"question-101".to_string()));
+    assert!(!unrated.contains(&"question-103".to_string()));
+}
@@ -0,0 +1,103 @@
+use leetcode_core::types::{submission_details, submission_list};
+use leetcode_tui_db::models::submission::DbSubmission;
+
+static LIST_JSON: &str = r#"{
+    "data": {
+        "questionSubmissionList": {
+            "lastKey": null,
+            "hasNext": false,
+            "submissions": [
+                {
+                    "id": "1080234567",
+                    "statusDisplay": "Accepted",
+                    "lang": "python3",
+                    "runtime": "52 ms",
+                    "memory": "17.2 MB",
+                    "timestamp": "1698000000"
+                },
+                {
+                    "id": "1080234000",
+                    "statusDisplay": "Wrong Answer",
+                    "lang": "rust",
+                    "runtime": "N/A",
+                    "memory": "N/A",
+                    "timestamp": "1697990000"
+                }
+            ]
+        }
+    }
+}"#;
+
+static DETAILS_JSON: &str = r#"{
+    "data": {
+        "submissionDetails": {
+            "runtime": 52,
+            "runtimeDisplay": "52 ms",
+            "runtimePercentile": 80.5,
+            "runtimeDistribution": "{\"lang\": \"python3\", \"distribution\": [[\"40\", 2.5], [\"50\", 10.1]]}",
+            "memory": 17200000,
+            "memoryDisplay": "17.2 MB",
+            "memoryPercentile": 60.1,
+            "memoryDistribution": null,
+            "code": "class Solution:\n    pass",
+            "timestamp": 1698000000,
+            "statusCode": 10,
+            "lang": {"name": "python3", "verboseName": "Python3"},
+            "question": {"questionId": "1", "titleSlug": "two-sum"}
+        }
+    }
+}"#;
+
+fn populate_db() {
+    let root: submission_list::Root = serde_json::from_str(LIST_JSON).unwrap();
+    let submissions = root
+        .get_submissions()
+        .into_iter()
+        .map(|s| DbSubmission::new(1, s))
+        .collect();
+    DbSubmission::save_multiple_to_db(submissions).unwrap();
+}
+
+#[test]
+fn test_should_fetch_submissions_of_a_question_latest_first() {
+    leetcode_tui_db::init(None).unwrap();
+    populate_db();
+
+    let submissions = DbSubmission::fetch_by_question(1).unwrap();
+    assert_eq!(submissions.len(), 2);
+    assert_eq!(submissions[0].id, 1080234567);
+    assert!(submissions[0].is_accepted());
+    assert!(!submissions[1].is_accepted());
+    assert_eq!(
+        submissions[0].to_string(),
+        "2023-10-22 18:40  Accepted              python3     52 ms     17.2 MB"
+    );
+
+    assert!(DbSubmission::fetch_by_question(2).unwrap().is_empty());
+}
+
+#[test]
+fn test_details_should_survive_a_list_resync() {
+    leetcode_tui_db::init(None).unwrap();
+    populate_db();
+
+    let root: submission_details::Root = serde_json::from_str(DETAILS_JSON).unwrap();
+    let mut submission = DbSubmission::get_submission_by_id(1080234567)
+        .unwrap()
+        .unwrap();
+    submission
+        .set_details(root.data.submission_details.into())
+        .unwrap();
+
+    // list sync does not carry details
+    populate_db();
+
+    let submission = DbSubmission::get_submission_by_id(1080234567)
+        .unwrap()
+        .unwrap();
+    let details = submission.details.unwrap();
+    assert_eq!(details.code, "class Solution:\n    pass");
+    assert_eq!(details.runtime_percentile, Some(80.5));
+    assert_eq!(details.runtime_distribution.len(), 2);
+    assert!(details.memory_distribution.is_empty());
+}
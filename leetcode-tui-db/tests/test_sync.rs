@@ -0,0 +1,75 @@
+mod common;
+
+use common::question;
+use leetcode_tui_db::models::question::sync::{Retag, StatusChange};
+use leetcode_tui_db::{DbQuestion, DbTopic};
+
+fn topic_ids(slug: &str) -> Vec<u32> {
+    DbTopic::new(slug).question_ids().unwrap().unwrap()
+}
+
+#[test]
+fn test_resync_reports_changes_and_reconciles_topics() {
+    leetcode_tui_db::init(None).unwrap();
+    let summary = DbQuestion::save_multiple_to_db(vec![
+        question(1, None, &["array"]),
+        question(2, Some("notac"), &["array", "trees"]),
+    ])
+    .unwrap();
+    assert_eq!(
+        summary.new,
+        vec![(1, "Question 1".into()), (2, "Question 2".into())]
+    );
+    assert!(summary.status_changes.is_empty());
+    assert!(summary.retagged.is_empty());
+
+    // an unchanged resync changes nothing
+    let summary = DbQuestion::save_multiple_to_db(vec![
+        question(1, None, &["array"]),
+        question(2, Some("notac"), &["array", "trees"]),
+    ])
+    .unwrap();
+    assert!(summary.is_empty());
+
+    let summary = DbQuestion::save_multiple_to_db(vec![
+        question(1, None, &["array"]),
+        question(2, Some("ac"), &["array", "graph"]),
+        question(3, None, &["graph"]),
+    ])
+    .unwrap();
+    assert_eq!(summary.new, vec![(3, "Question 3".into())]);
+    assert_eq!(
+        summary.status_changes,
+        vec![StatusChange {
+            id: 2,
+            title: "Question 2".into(),
+            from: Some("notac".into()),
+            to: Some("ac".into()),
+        }]
+    );
+    assert_eq!(
+        summary.retagged,
+        vec![Retag {
+            id: 2,
+            title: "Question 2".into(),
+            added: vec!["graph".into()],
+            removed: vec!["trees".into()],
+        }]
+    );
+    assert_eq!(summary.removed_topics, vec!["trees".to_string()]);
+
+    assert_eq!(topic_ids("array"), vec![1, 2]);
+    assert_eq!(topic_ids("graph"), vec![2, 3]);
+    assert!(topic_ids("trees").is_empty());
+    let mut topics = DbTopic::fetch_all()
+        .unwrap()
+        .into_iter()
+        .map(|t| t.slug)
+        .collect::<Vec<_>>();
+    topics.sort();
+    assert_eq!(topics, vec!["array", "graph"]);
+    assert_eq!(
+        DbQuestion::get_question_by_id(2).unwrap().unwrap().status,
+        Some("ac".into())
+    );
+}
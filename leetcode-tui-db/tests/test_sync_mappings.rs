@@ -0,0 +1,40 @@
+mod common;
+
+use common::question;
+use leetcode_tui_db::{DbQuestion, DbTopic};
+
+fn ids(topic: &str) -> Vec<u32> {
+    DbTopic::new(topic)
+        .fetch_questions()
+        .unwrap()
+        .iter()
+        .map(|q| q.id)
+        .collect()
+}
+
+fn has_topic(slug: &str) -> bool {
+    DbTopic::fetch_all().unwrap().iter().any(|t| t.slug == slug)
+}
+
+#[test]
+fn test_stale_mappings_are_reconciled() {
+    leetcode_tui_db::init(None).unwrap();
+    DbQuestion::save_multiple_to_db(vec![question(7001, None, &["kept", "stale"])]).unwrap();
+
+    // the question no longer names `stale`, its mapping is left over
+    let mut stored = DbQuestion::get_question_by_id(7001).unwrap().unwrap();
+    stored.topics = vec![DbTopic::new("kept")];
+    stored.mark_attempted().unwrap();
+    assert_eq!(ids("stale"), [7001]);
+
+    // a single save fixes the mappings without sweeping the topics
+    question(7001, None, &["kept"]).save_to_db().unwrap();
+    assert!(ids("stale").is_empty());
+    assert_eq!(ids("kept"), [7001]);
+    assert!(has_topic("stale"));
+
+    let summary = DbQuestion::save_multiple_to_db(vec![]).unwrap();
+    assert_eq!(summary.removed_topics, ["stale"]);
+    assert!(!has_topic("stale"));
+    assert!(has_topic("kept"));
+}
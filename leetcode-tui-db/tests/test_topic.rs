@@ -1,7 +1,7 @@
 use leetcode_core::types::problemset_question_list::Root;
 use leetcode_tui_db::models::{question::DbQuestion, topic::DbTopic};
 
-static JSON: &'static str = r#"{
+static JSON: &str = r#"{
             "data": {
                 "problemsetQuestionList": {
                     "total": 2777,
@@ -91,7 +91,7 @@ static JSON: &'static str = r#"{
             }
         }"#;
 
-fn populate_db<'a>() {
+fn populate_db() {
     let root: Root = serde_json::from_str(JSON).unwrap();
     let mut questions = root.get_questions();
     while let Some(quest) = questions.pop() {
@@ -101,7 +101,7 @@ fn populate_db<'a>() {
 
 #[test]
 fn test_should_fetch_all_topics_from_the_db() {
-    leetcode_tui_db::init(None);
+    leetcode_tui_db::init(None).unwrap();
     populate_db();
     let topics = DbTopic::fetch_all().unwrap();
     assert_eq!(topics.len(), 2);
@@ -110,7 +110,7 @@ fn test_should_fetch_all_topics_from_the_db() {
 #[test]
 fn test_should_fetch_all_questions_for_a_topic() {
     // Initialize the model
-    leetcode_tui_db::init(None);
+    leetcode_tui_db::init(None).unwrap();
 
     populate_db();
 
@@ -1,10 +1,12 @@
-use crate::utils::update_database_questions;
+use crate::utils::{sync_database_incrementally, sync_summary_lines, update_database_questions};
 use crate::{ctx::Ctx, executor::Executor, signals::Signals, widgets::root::Root};
 use color_eyre::Result;
-use leetcode_tui_config::{constants::EDITOR, key::Key};
-use leetcode_tui_core::{emit, Event, UBStrSender};
+use leetcode_tui_config::{constants::EDITOR, key::Key, log, CONFIG};
+use leetcode_tui_core::session::Session;
+use leetcode_tui_core::{emit, Event, SendError, UBStrSender};
 use leetcode_tui_db::{DbQuestion, DbTopic};
 use leetcode_tui_shared::tui::Term;
+use std::rc::Rc;
 
 pub struct App {
     cx: super::ctx::Ctx,
@@ -22,13 +24,19 @@ impl App {
             signals,
         };
         emit!(Render);
+        Session::validate();
+        if CONFIG.as_ref().db.sync_on_startup {
+            Self::spawn_incremental_sync();
+        }
         while let Some(event) = app.signals.recv().await {
             match event {
                 Event::Quit => {
                     // app.dispatch_quit();
                     break;
                 }
-                Event::Input(sender, default_input) => app.dispatch_input(sender, default_input),
+                Event::Input(sender, default_input, prompt) => {
+                    app.dispatch_input(sender, default_input, prompt)
+                }
                 Event::Key(key) => app.dispatch_key(key),
                 Event::Render(_) => app.dispatch_render(),
                 Event::Topic(topic) => app.dispatch_topic_update(topic),
@@ -40,6 +48,10 @@ impl App {
                     app.dispatch_select_popup(maybe_title, lines, result_sender)
                 }
                 Event::Error(e) => app.dispatch_popup(Some("Error".into()), vec![e]),
+                Event::SignedOut(e) => app.dispatch_popup(
+                    Some("Error".into()),
+                    vec![e, String::new(), "Press U to update the cookies".into()],
+                ),
                 Event::Open(file_path) => app.dispatch_opener(file_path),
                 Event::SyncDb => app.dispatch_db_update().await,
                 Event::ProgressUpdate(title, progress, total) => {
@@ -63,16 +75,27 @@ impl App {
     }
 
     fn dispatch_topic_update(&mut self, topic: DbTopic) {
-        let questions = topic.fetch_questions().unwrap();
+        let Ok(questions) = self.cx.index.questions(&topic).emit_if_error() else {
+            return;
+        };
         self.cx.content.get_topic_mut().set_topic(&topic);
-        self.dispatch_question_update(questions);
+        self.cx.content.get_questions_mut().set_questions(questions);
+        emit!(Render);
     }
 
     fn dispatch_question_update(&mut self, questions: Vec<DbQuestion>) {
-        self.cx.content.get_questions_mut().set_questions(questions);
+        self.cx
+            .content
+            .get_questions_mut()
+            .set_questions(questions.into_iter().map(Rc::new).collect());
         emit!(Render);
     }
 
+    /// Picks up the changes a sync made to the db, a full scan.
+    fn rebuild_index(&mut self) {
+        self.cx.index.rebuild().emit_if_error().ok();
+    }
+
     fn dispatch_add_question(&mut self, questions: Vec<DbQuestion>) {
         for ques in questions {
             self.cx.content.get_questions_mut().add_question(ques);
@@ -112,7 +135,7 @@ impl App {
             let editor = EDITOR.get().expect("editor not set");
             std::process::Command::new("sh")
                 .arg("-c")
-                .arg(&format!(
+                .arg(format!(
                     r#"{} "{}""#,
                     editor,
                     file_path.as_os_str().to_str().unwrap()
@@ -130,15 +153,41 @@ impl App {
     fn dispatch_module_event(&mut self, e: Event) {
         match e {
             Event::QuestionFilter(needle) => self.cx.content.get_questions_mut().filter_by(needle),
-            Event::QuestionUpdate => self.cx.content.get_topic().notify_change(),
+            Event::QuestionUpdate(question) => {
+                self.cx.index.insert(question);
+                self.cx
+                    .content
+                    .get_topic_mut()
+                    .refresh_progress(&self.cx.index);
+                self.cx.content.get_topic().notify_change()
+            }
+            Event::TopicsUpdate => {
+                self.rebuild_index();
+                self.cx.content.get_topic_mut().refresh(&self.cx.index)
+            }
+            Event::QuestionsSynced => {
+                self.rebuild_index();
+                self.cx.content.get_topic_mut().refresh(&self.cx.index);
+                self.cx.content.get_topic().notify_change()
+            }
+            Event::RatingsUpdate => self.cx.content.get_questions_mut().reload_like_ratios(),
+            Event::UserStatus(status) => {
+                self.cx.session.set_status(status);
+            }
             _ => (),
         }
         emit!(Render);
     }
 
-    fn dispatch_input(&mut self, sender: UBStrSender, default_input: Option<String>) {
-        self.cx.input.toggle();
-        self.cx.input.reset_with(sender, default_input);
+    fn dispatch_input(
+        &mut self,
+        sender: UBStrSender,
+        default_input: Option<String>,
+        prompt: Option<String>,
+    ) {
+        // a new prompt replaces the open one, e.g. the cookie form while searching
+        self.cx.input.visible = true;
+        self.cx.input.reset_with(sender, default_input, prompt);
         emit!(Render);
     }
 
@@ -149,14 +198,45 @@ impl App {
 
     async fn dispatch_db_update(&mut self) {
         tokio::spawn(async move {
-            update_database_questions(true).await.unwrap();
+            let summary = match update_database_questions(true).await {
+                Ok(summary) => summary,
+                Err(e) => {
+                    // hides the progress bar of the aborted sync
+                    emit!(ProgressUpdate("Syncing db...".into(), 0, 0));
+                    emit!(Error(e.to_string()));
+                    return;
+                }
+            };
+            emit!(TopicsUpdate);
             emit!(Topic(DbTopic {
                 slug: "all".to_string()
             }));
+            emit!(Popup("Sync finished", sync_summary_lines(&summary)));
             emit!(Render);
         });
     }
 
+    /// The tui works off the last sync meanwhile, hence a failure is only logged.
+    fn spawn_incremental_sync() {
+        tokio::spawn(async move {
+            let summary = match sync_database_incrementally().await {
+                Ok(summary) => summary,
+                Err(e) => {
+                    log::error!("could not sync the db in the background: {e}");
+                    return;
+                }
+            };
+            if summary.is_empty() {
+                return;
+            }
+            emit!(QuestionsSynced);
+            emit!(Popup(
+                "Synced in the background",
+                sync_summary_lines(&summary)
+            ));
+        });
+    }
+
     fn dispatch_progress_update(&mut self, title: String, progress: u32, total: u32) {
         self.cx.progress.set_progress(title, progress, total);
         emit!(Render);
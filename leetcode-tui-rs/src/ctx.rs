@@ -3,6 +3,8 @@ use leetcode_tui_core::help::Help;
 use leetcode_tui_core::input::Input;
 use leetcode_tui_core::popup::{Popup, SelectPopup};
 use leetcode_tui_core::progress;
+use leetcode_tui_core::session::Session;
+use leetcode_tui_db::QuestionIndex;
 
 pub struct Ctx {
     pub content: MainContent,
@@ -11,19 +13,24 @@ pub struct Ctx {
     pub input: Input,
     pub help: Help,
     pub progress: progress::ProgressCtx,
+    pub session: Session,
+    pub index: QuestionIndex,
 }
 
 impl Ctx {
     pub(super) async fn new() -> Self {
         let progress = progress::ProgressCtx::default();
+        let index = QuestionIndex::build().expect("Could not load the questions from the db");
 
         Self {
-            content: MainContent::new().await,
+            content: MainContent::new(&index).await,
             popup: Default::default(),
             select_popup: Default::default(),
             input: Default::default(),
             help: Default::default(),
             progress,
+            session: Default::default(),
+            index,
         }
     }
 }
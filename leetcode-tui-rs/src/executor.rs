@@ -19,8 +19,8 @@ impl Executor {
 
         if cx.help.is_visible() {
             return match key {
-                Key::Down | Key::Char('j') => cx.help.next(),
-                Key::Up | Key::Char('k') => cx.help.previous(),
+                Key::Down | Key::Char('j') => cx.help.next_item(),
+                Key::Up | Key::Char('k') => cx.help.prev_item(),
                 Key::Char('?') | Key::Esc => cx.help.toggle(),
                 Key::Enter => {
                     cx.help.toggle()
@@ -53,6 +53,7 @@ impl Executor {
         if cx.input.visible {
             return match key {
                 Key::Esc => cx.input.close(),
+                Key::Enter => cx.input.submit(),
                 Key::Char(c) => cx.input.char(c),
                 Key::Backspace => cx.input.remove_char(),
                 Key::Up | Key::Down => {
@@ -80,15 +81,25 @@ impl Executor {
                 Key::Char('T') => cx.content.get_topic_mut().prev_topic(),
                 Key::Char('t') => cx.content.get_topic_mut().next_topic(),
                 Key::Char('d') => cx.content.get_questions().toggle_daily_question(),
+                Key::Char('D') => cx.content.get_questions().show_daily_calendar(),
+                Key::Char('M') => cx.content.get_questions().catch_up_daily_question(),
                 Key::Char('e') => cx.content.get_questions_mut().solve_for_language(),
+                Key::Char('E') => cx.content.get_questions().edit_testcases(),
                 Key::Up | Key::Char('k') => cx.content.get_questions_mut().prev_ques(),
                 Key::Down | Key::Char('j') => cx.content.get_questions_mut().next_ques(),
                 Key::Char('r') => cx.content.get_questions_mut().rand_ques(),
                 Key::Enter => cx.content.get_questions_mut().show_question_content(),
                 Key::Char('R') => cx.content.get_questions_mut().run_solution(),
                 Key::Char('s') => cx.content.get_questions_mut().submit_solution(),
+                Key::Char('x') => cx.content.get_questions_mut().cancel_judge(),
+                Key::Char('h') => cx.content.get_questions().show_submissions(),
+                Key::Char('f') => cx.content.get_questions().toggle_favorite(),
                 Key::Ctrl('s') => cx.content.get_questions_mut().toggle_stats(),
                 Key::Char('/') => cx.content.get_questions_mut().toggle_search(),
+                Key::Char('L') => cx.content.get_questions_mut().toggle_sort_by_likes(),
+                Key::Char('I') => cx.content.get_topic().import_list(),
+                Key::Char('X') => cx.content.get_topic().export_list(),
+                Key::Char('U') => cx.session.update_credentials(),
                 Key::Char('q') => {
                     emit!(Quit);
                     false
@@ -1,14 +1,52 @@
 use color_eyre::Result;
-use leetcode_tui_config::CONFIG;
-use leetcode_tui_db;
+use leetcode_core::graphql::query::language_list;
+use leetcode_tui_config::utils::get_languages_file_path;
+use leetcode_tui_config::{log, CONFIG};
 use leetcode_tui_rs::app::App;
 use leetcode_tui_rs::utils::update_database_questions;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     leetcode_tui_config::init().await?;
-    leetcode_tui_db::init(Some(&CONFIG.as_ref().db.path));
-    leetcode_core::init(&CONFIG.as_ref().csrftoken, &CONFIG.as_ref().lc_session).await?;
+    leetcode_tui_db::init_with_progress(Some(&CONFIG.as_ref().db.path), |progress| {
+        println!(
+            "Migrating the database ({}/{}): {}",
+            progress.step, progress.total, progress.migration.description
+        )
+    })?;
+    leetcode_core::init(
+        &CONFIG.as_ref().csrftoken,
+        &CONFIG.as_ref().lc_session,
+        CONFIG.as_ref().host(),
+        CONFIG.as_ref().network.options(),
+    )
+    .await?;
+    if let Some(cassette) = &CONFIG.as_ref().cassette {
+        leetcode_core::graphql::cassette::init(&cassette.path, cassette.mode)?;
+    }
+    leetcode_core::graphql::retry::init(CONFIG.as_ref().retry.policy());
+    let cache = &CONFIG.as_ref().cache;
+    if cache.max_size_mb > 0 {
+        leetcode_core::graphql::cache::init(&cache.path, cache.max_size_mb * 1024 * 1024)?;
+    }
+    leetcode_core::types::language::init(CONFIG.as_ref().languages.clone());
+    // the solution files are read with the languages saved by the last run and
+    // the list is refreshed in the background, only the first run waits for it
+    let languages_file = get_languages_file_path();
+    let is_saved = languages_file.exists();
+    if let Err(e) = language_list::load_registry(&languages_file) {
+        log::error!("could not load the saved language list: {e}");
+    }
+    let update_languages = async move {
+        if let Err(e) = language_list::update_registry(&languages_file).await {
+            log::error!("could not fetch the language list: {e}");
+        }
+    };
+    if is_saved {
+        tokio::spawn(update_languages);
+    } else {
+        update_languages.await;
+    }
     leetcode_tui_core::init();
     update_database_questions(false).await?;
     App::run().await
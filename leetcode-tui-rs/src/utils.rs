@@ -1,22 +1,27 @@
-use api::{GQLLeetcodeRequest, QuestionRequest};
+use api::graphql::query::problemset_question_list::{OrderBy, SortOrder};
+use api::{GQLLeetcodeRequest, QuestionRequest, StudyPlanRequest, UserProgressRequest};
 use color_eyre::Result;
 use kdam::BarExt;
 use leetcode_core as api;
+use leetcode_tui_config::{log, CONFIG};
 use leetcode_tui_core::emit;
-use leetcode_tui_db::DbQuestion;
+use leetcode_tui_db::models::list::ListKind;
+use leetcode_tui_db::models::question::sync::{DbSyncState, Progress, SyncSummary};
+use leetcode_tui_db::{DbList, DbQuestion};
+use leetcode_tui_shared::date::parse_rfc3339;
 
 fn should_update_db(runs_inside_tui: bool) -> Result<bool> {
     let first_time_start = DbQuestion::get_total_questions()? == 0 && !runs_inside_tui;
     if first_time_start || runs_inside_tui {
-        return Ok(true);
+        Ok(true)
     } else {
-        return Ok(false);
+        Ok(false)
     }
 }
 
-pub async fn update_database_questions(runs_inside_tui: bool) -> Result<()> {
+pub async fn update_database_questions(runs_inside_tui: bool) -> Result<SyncSummary> {
     if !should_update_db(runs_inside_tui)? {
-        return Ok(());
+        return Ok(SyncSummary::default());
     }
 
     let query = api::QuestionRequest::default();
@@ -34,14 +39,14 @@ pub async fn update_database_questions(runs_inside_tui: bool) -> Result<()> {
 
         // Here you would typically use .skip(skip).take(take) on your data source
         let join_handle = tokio::spawn(async move {
-            let resp = QuestionRequest::new(take, skip).send().await.unwrap();
+            let resp = QuestionRequest::new(take, skip).send().await?;
             let questions = resp.get_questions();
-            let db_questions = questions
-                .into_iter()
-                .map(|q| q.try_into().unwrap())
-                .collect::<Vec<DbQuestion>>();
 
-            db_questions
+            let questions = questions
+                .into_iter()
+                .map(|q| q.try_into())
+                .collect::<Result<Vec<DbQuestion>, _>>()?;
+            Ok::<_, color_eyre::Report>(questions)
         });
 
         handles.push(join_handle);
@@ -50,8 +55,7 @@ pub async fn update_database_questions(runs_inside_tui: bool) -> Result<()> {
     let mut cli_progress_bar = kdam::tqdm!(total = total_questions as usize);
     let mut all_questions = vec![];
     for handle in handles {
-        let questions_result = handle.await.unwrap();
-        all_questions.extend(questions_result);
+        all_questions.extend(handle.await??);
 
         // update progress bar
         if runs_inside_tui {
@@ -64,10 +68,226 @@ pub async fn update_database_questions(runs_inside_tui: bool) -> Result<()> {
             ))
         } else {
             // kdam
-            cli_progress_bar.update(chunk_size as usize).unwrap();
+            cli_progress_bar.update(chunk_size as usize)?;
+        }
+    }
+
+    let summary = DbQuestion::save_multiple_to_db(all_questions)?;
+    update_database_lists().await;
+    Ok(summary)
+}
+
+/// Page size of the requests made by the incremental sync.
+const INCREMENTAL_PAGE_SIZE: i32 = 100;
+
+/// Brings the db up to date without refetching the whole problemset: the
+/// questions above the highest stored id and the statuses of the questions
+/// submitted since the last sync.
+///
+/// Questions published with a frontend id below the highest stored one are
+/// not picked up, only a full sync (`*`) finds them.
+pub async fn sync_database_incrementally() -> Result<SyncSummary> {
+    let mut summary = DbQuestion::save_multiple_to_db(fetch_new_questions().await?)?;
+    summary.extend(DbQuestion::apply_progress(&fetch_progress().await?)?);
+    Ok(summary)
+}
+
+/// Questions above the highest stored frontend id, listed from the newest
+/// until the first known id.
+async fn fetch_new_questions() -> Result<Vec<DbQuestion>> {
+    let max_id = DbQuestion::max_id()?.unwrap_or_default();
+    let mut new_questions = vec![];
+    let mut skip = 0;
+    loop {
+        let page = QuestionRequest::new(INCREMENTAL_PAGE_SIZE, skip)
+            .order_by(OrderBy::FrontendId, SortOrder::Descending)
+            .send()
+            .await?
+            .get_questions();
+        let page_len = page.len() as i32;
+        for question in page {
+            let question: DbQuestion = question.try_into()?;
+            if question.id <= max_id {
+                return Ok(new_questions);
+            }
+            new_questions.push(question);
+        }
+        if page_len < INCREMENTAL_PAGE_SIZE {
+            return Ok(new_questions);
+        }
+        skip += page_len;
+    }
+}
+
+fn parse_submitted_at(timestamp: &str) -> Result<i64> {
+    parse_rfc3339(timestamp)
+        .ok_or_else(|| color_eyre::eyre::eyre!("Invalid lastSubmittedAt: {timestamp}"))
+}
+
+/// Progress of the questions submitted since the last sync, the most recent first.
+async fn fetch_progress() -> Result<Vec<Progress>> {
+    let cursor = DbSyncState::last_submitted_at()?
+        .map(|c| parse_submitted_at(&c))
+        .transpose()?;
+    let mut progress = vec![];
+    let mut skip = 0;
+    loop {
+        let page = UserProgressRequest::new(INCREMENTAL_PAGE_SIZE, skip)
+            .send()
+            .await?
+            .data
+            .user_progress_question_list;
+        let page_len = page.questions.len() as i32;
+        for question in page.questions {
+            // submissions at the cursor are read again, applying them twice is harmless
+            let submitted_at = parse_submitted_at(&question.last_submitted_at)?;
+            if cursor.is_some_and(|c| submitted_at < c) {
+                return Ok(progress);
+            }
+            let status @ Some(_) = question.question_status.question_status() else {
+                continue;
+            };
+            progress.push(Progress {
+                id: question.frontend_id.parse()?,
+                status,
+                last_submitted_at: question.last_submitted_at,
+            });
+        }
+        skip += page_len;
+        if page_len < INCREMENTAL_PAGE_SIZE || skip >= page.total_num {
+            return Ok(progress);
         }
     }
+}
+
+/// Number of entries listed per section of the sync summary popup.
+const SUMMARY_SECTION_LIMIT: usize = 50;
+
+fn status_name(status: &Option<String>) -> &'static str {
+    match status.as_deref() {
+        Some("ac") => "accepted",
+        Some(_) => "attempted",
+        None => "todo",
+    }
+}
+
+fn push_section(lines: &mut Vec<String>, title: &str, entries: Vec<String>) {
+    if entries.is_empty() {
+        return;
+    }
+    lines.push(format!("{title} ({})", entries.len()));
+    let hidden = entries.len().saturating_sub(SUMMARY_SECTION_LIMIT);
+    lines.extend(entries.into_iter().take(SUMMARY_SECTION_LIMIT));
+    if hidden > 0 {
+        lines.push(format!("  … and {hidden} more"));
+    }
+    lines.push(String::new());
+}
+
+/// Lines of the popup shown once a sync from the tui finishes.
+pub fn sync_summary_lines(summary: &SyncSummary) -> Vec<String> {
+    if summary.is_empty() {
+        return vec!["Everything is up to date".into()];
+    }
+    let mut lines = vec![];
+    push_section(
+        &mut lines,
+        "New questions",
+        summary
+            .new
+            .iter()
+            .map(|(id, title)| format!("  {id}. {title}"))
+            .collect(),
+    );
+    push_section(
+        &mut lines,
+        "Status changes",
+        summary
+            .status_changes
+            .iter()
+            .map(|c| {
+                format!(
+                    "  {}. {}: {} -> {}",
+                    c.id,
+                    c.title,
+                    status_name(&c.from),
+                    status_name(&c.to)
+                )
+            })
+            .collect(),
+    );
+    push_section(
+        &mut lines,
+        "Retagged questions",
+        summary
+            .retagged
+            .iter()
+            .map(|r| {
+                let tags = r
+                    .added
+                    .iter()
+                    .map(|t| format!("+{t}"))
+                    .chain(r.removed.iter().map(|t| format!("-{t}")))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("  {}. {}: {tags}", r.id, r.title)
+            })
+            .collect(),
+    );
+    push_section(
+        &mut lines,
+        "Removed topics",
+        summary
+            .removed_topics
+            .iter()
+            .map(|t| format!("  {t}"))
+            .collect(),
+    );
+    lines.pop();
+    lines
+}
+
+/// Syncs the configured study plans and favorite lists. A list that cannot be
+/// fetched, e.g. a private one, keeps its last synced questions.
+async fn update_database_lists() {
+    let lists = &CONFIG.as_ref().lists;
+    for slug in &lists.study_plans {
+        if let Err(e) = update_study_plan(slug).await {
+            log::error!("could not sync study plan {slug}: {e}");
+        }
+    }
+    for favorite in &lists.favorites {
+        let name = favorite.name.as_deref().unwrap_or(&favorite.id);
+        if let Err(e) = update_favorite_list(&favorite.id, name).await {
+            log::error!("could not sync favorite list {}: {e}", favorite.id);
+        }
+    }
+}
+
+async fn update_study_plan(slug: &str) -> Result<()> {
+    let plan = StudyPlanRequest::new(slug).send().await?.data.study_plan;
+    let question_ids = plan
+        .questions()
+        .map(|q| q.question_frontend_id.parse())
+        .collect::<Result<Vec<u32>, _>>()?;
+    DbList::new(slug, &plan.name, ListKind::StudyPlan).save_with_questions(&question_ids)?;
+    Ok(())
+}
 
-    DbQuestion::save_multiple_to_db(all_questions);
+async fn update_favorite_list(list_id: &str, name: &str) -> Result<()> {
+    let total = QuestionRequest::new(1, 0)
+        .list_id(list_id)
+        .send()
+        .await?
+        .get_total_questions();
+    let question_ids = QuestionRequest::new(total, 0)
+        .list_id(list_id)
+        .send()
+        .await?
+        .get_questions()
+        .into_iter()
+        .map(|q| q.frontend_question_id.parse())
+        .collect::<Result<Vec<u32>, _>>()?;
+    DbList::new(list_id, name, ListKind::Favorite).save_with_questions(&question_ids)?;
     Ok(())
 }
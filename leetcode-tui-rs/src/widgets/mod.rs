@@ -3,5 +3,6 @@ pub mod popup;
 pub mod progress_bar;
 pub mod question;
 pub mod root;
+mod session;
 mod stats;
 pub mod topic;
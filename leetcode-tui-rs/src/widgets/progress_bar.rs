@@ -12,7 +12,12 @@ impl<'a> ProgressBar<'a> {
         Self { cx }
     }
 
-    fn create_gauge_without_block(title: &str, val: usize, total: usize, style: Style) -> Gauge {
+    fn create_gauge_without_block(
+        title: &str,
+        val: usize,
+        total: usize,
+        style: Style,
+    ) -> Gauge<'_> {
         let percentage = if total != 0 {
             (val as f32 / total as f32) * 100_f32
         } else {
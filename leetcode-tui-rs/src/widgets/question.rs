@@ -29,7 +29,12 @@ impl<'a> Questions<'a> {
         let medium = normal.medium.into();
         let hard = normal.hard.into();
 
-        ListItem::new(q.to_string()).style(if q.id == hovered.id {
+        let bookmark = if q.is_favor { "🔖" } else { "  " };
+        let ac_rate = q
+            .ac_rate
+            .map(|rate| format!(" ({rate:.1}%)"))
+            .unwrap_or_default();
+        ListItem::new(format!("{bookmark}{q}{ac_rate}")).style(if q.id == hovered.id {
             if q.is_easy() {
                 easy_hovered
             } else if q.is_medium() {
@@ -72,7 +77,7 @@ impl<'a> Widget for Questions<'a> {
             .border_type(BorderType::Rounded)
             .border_style(medium)
             .cyan()
-            .title("Questions")
+            .title(self.cx.content.get_questions().title())
             .title_alignment(Alignment::Center);
 
         let term_window = self.get_window();
@@ -87,7 +92,7 @@ impl<'a> Widget for Questions<'a> {
                 buf,
             );
             if self.cx.content.get_questions().is_stats_visible() {
-                stats::Stats::new(&self.cx.content.get_questions())
+                stats::Stats::new(self.cx.content.get_questions())
                     .render(term_window.root.q_stats.outer, buf);
             }
         }
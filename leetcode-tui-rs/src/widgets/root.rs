@@ -8,6 +8,7 @@ use crate::widgets::help::Help;
 use crate::widgets::popup::{Popup, SelectPopup};
 use crate::widgets::progress_bar::ProgressBar;
 use crate::widgets::question::Questions;
+use crate::widgets::session::Session;
 use crate::widgets::topic::Topic;
 
 pub struct Root<'a> {
@@ -35,6 +36,9 @@ impl<'a> Widget for Root<'a> {
             Paragraph::new(line)
                 .alignment(Alignment::Right)
                 .render(self.get_window().root.status_bar.message_area, buf);
+            if !self.cx.input.visible {
+                Session::new(self.cx).render(_area, buf);
+            }
         }
 
         Topic::new(self.cx).render(_area, buf);
@@ -49,7 +53,7 @@ impl<'a> Widget for Root<'a> {
         }
 
         if self.cx.input.visible {
-            let mut search_text: String = "/".into();
+            let mut search_text: String = self.cx.input.prompt().into();
             if let Some(input_text) = self.cx.input.text() {
                 search_text.push_str(input_text);
             }
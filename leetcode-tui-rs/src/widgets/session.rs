@@ -0,0 +1,47 @@
+use crate::ctx::Ctx;
+use leetcode_tui_config::CONFIG;
+use leetcode_tui_shared::layout::GetWindowStats;
+use ratatui::prelude::*;
+use ratatui::widgets::{Paragraph, Widget};
+
+pub(super) struct Session<'a> {
+    cx: &'a Ctx,
+}
+
+impl<'a> Session<'a> {
+    pub fn new(cx: &'a Ctx) -> Self {
+        Self { cx }
+    }
+}
+
+impl<'a> Widget for Session<'a> {
+    fn render(self, _area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        // nothing to show until the startup check comes back
+        let Some(status) = self.cx.session.status() else {
+            return;
+        };
+        let defaults = &CONFIG.as_ref().theme.defaults;
+        let mut spans = vec![];
+        if status.is_signed_in {
+            spans.push(Span::styled(
+                format!(" {} ", status.username()),
+                Style::default().fg(defaults.fg.into()),
+            ));
+            if status.is_premium() {
+                spans.push(Span::styled(
+                    " Premium ",
+                    Style::default()
+                        .bg(defaults.info.into())
+                        .fg(defaults.terminal_black.into()),
+                ));
+            }
+        } else {
+            spans.push(Span::styled(
+                " Not signed in, press U to update the cookies ",
+                Style::default().fg(defaults.comment.into()),
+            ));
+        }
+        Paragraph::new(Line::from(spans))
+            .render(self.get_window().root.status_bar.search_area, buf);
+    }
+}
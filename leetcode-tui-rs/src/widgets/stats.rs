@@ -14,7 +14,7 @@ impl<'a> Stats<'a> {
 }
 
 impl<'a> Stats<'a> {
-    fn create_block(title: &str) -> Block {
+    fn create_block(title: &str) -> Block<'_> {
         Block::default()
             .borders(Borders::ALL)
             .title(Span::styled(
@@ -25,7 +25,7 @@ impl<'a> Stats<'a> {
             .cyan()
     }
 
-    pub fn create_gauge(title: &str, val: usize, total: usize, style: Style) -> Gauge {
+    pub fn create_gauge(title: &str, val: usize, total: usize, style: Style) -> Gauge<'_> {
         let block_title = format!("{}: {}/{}", title, val, total);
         let percentage = if total != 0 {
             (val as f32 / total as f32) * 100_f32
@@ -49,7 +49,12 @@ impl<'a> Stats<'a> {
 
 impl<'a> Widget for Stats<'a> {
     fn render(self, _area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
-        let block = Self::create_block("Stats");
+        let stats = self.cx.get_stats();
+        let title = match stats.get_average_ac_rate() {
+            Some(rate) => format!("Stats (avg. acceptance {rate:.1}%)"),
+            None => "Stats".to_string(),
+        };
+        let block = Self::create_block(&title);
 
         block.render(self.get_window().root.q_stats.outer, buf);
 
@@ -61,7 +66,11 @@ impl<'a> Widget for Stats<'a> {
 
         let left_partition = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .constraints([
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+            ])
             .split(horizontal_partition[0]);
 
         let right_partition = Layout::default()
@@ -74,10 +83,11 @@ impl<'a> Widget for Stats<'a> {
             .split(horizontal_partition[1]);
 
         for ((question_status, numerator, denominator), render_area) in
-            self.cx.get_stats().get_ratios().into_iter().zip(
+            stats.get_ratios().into_iter().zip(
                 [
                     left_partition[0],
                     left_partition[1],
+                    left_partition[2],
                     right_partition[0],
                     right_partition[1],
                     right_partition[2],
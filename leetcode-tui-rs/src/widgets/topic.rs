@@ -14,7 +14,7 @@ impl<'a> Topic<'a> {
         Self { cx }
     }
 
-    fn get_styled_block(&self) -> Block {
+    fn get_styled_block(&self) -> Block<'_> {
         let style: Style = CONFIG.as_ref().theme.border.normal.into();
         Block::default()
             .borders(Borders::ALL)
@@ -32,14 +32,12 @@ impl<'a> Widget for Topic<'a> {
             let c_hovered: Style = config.hovered.into();
             let normal = config.normal.into();
 
-            let lines = self
-                .cx
-                .content
-                .get_topic()
+            let topic = self.cx.content.get_topic();
+            let lines = topic
                 .window()
                 .iter()
                 .map(|t| {
-                    ListItem::new(t.slug.as_str()).style(if t.slug == hovered.slug {
+                    ListItem::new(topic.label(t)).style(if t.slug == hovered.slug {
                         c_hovered
                     } else {
                         normal
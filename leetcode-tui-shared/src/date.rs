@@ -0,0 +1,153 @@
+//! Civil dates in UTC without pulling in a date crate.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Today's date in UTC, the timezone the daily challenge rolls over in.
+pub fn today() -> (i32, u32, u32) {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    civil_from_days((secs / 86400) as i64)
+}
+
+pub fn format_date(year: i32, month: u32, day: u32) -> String {
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    (days_from_civil(next_year, next_month, 1) - days_from_civil(year, month, 1)) as u32
+}
+
+/// 0 is Monday
+pub fn weekday(year: i32, month: u32, day: u32) -> u32 {
+    // 1970-01-01 was a Thursday
+    (days_from_civil(year, month, day) + 3).rem_euclid(7) as u32
+}
+
+// http://howardhinnant.github.io/date_algorithms.html
+pub fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = year as i64 - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+pub fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u32, day as u32)
+}
+
+/// Formats unix seconds as `YYYY-MM-DD HH:MM` (UTC).
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (year, month, day) = civil_from_days((timestamp / 86400) as i64);
+    let secs_of_day = timestamp % 86400;
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60
+    )
+}
+
+/// Parses an RFC 3339 timestamp (`2024-05-01T10:20:30.123+02:00`) into unix
+/// seconds, fractions of a second are dropped.
+pub fn parse_rfc3339(input: &str) -> Option<i64> {
+    fn number<T: std::str::FromStr>(s: &str) -> Option<T> {
+        s.bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| s.parse().ok())
+            .flatten()
+    }
+
+    let (date, time) = input.split_once(['T', 't', ' '])?;
+    let mut date_parts = date.splitn(3, '-');
+    let year: i32 = number(date_parts.next()?)?;
+    let month: u32 = number(date_parts.next()?)?;
+    let day: u32 = number(date_parts.next()?)?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let (clock, offset) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+        (clock, 0)
+    } else {
+        let at = time.rfind(['+', '-'])?;
+        let (clock, offset) = time.split_at(at);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let (hours, minutes): (i64, i64) = (number(hours)?, number(minutes)?);
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+        (clock, sign * (hours * 3600 + minutes * 60))
+    };
+    let clock = match clock.split_once('.') {
+        Some((clock, fraction)) => {
+            number::<u64>(fraction)?;
+            clock
+        }
+        None => clock,
+    };
+    let mut clock_parts = clock.splitn(3, ':');
+    let hours: i64 = number(clock_parts.next()?)?;
+    let minutes: i64 = number(clock_parts.next()?)?;
+    let seconds: i64 = number(clock_parts.next()?)?;
+    // 60 is a leap second
+    if hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    Some(days_from_civil(year, month, day) * 86400 + hours * 3600 + minutes * 60 + seconds - offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_rfc3339("2024-05-01T10:20:30Z"),
+            Some(days_from_civil(2024, 5, 1) * 86400 + 37230)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-05-01T12:20:30.123456+02:00"),
+            parse_rfc3339("2024-05-01T10:20:30Z")
+        );
+        assert_eq!(
+            parse_rfc3339("2024-04-30T23:20:30-11:00"),
+            parse_rfc3339("2024-05-01T10:20:30Z")
+        );
+        // later instant, smaller string
+        assert!(parse_rfc3339("2024-05-01T09:00:00-05:00") > parse_rfc3339("2024-05-01T12:00:00Z"));
+        for invalid in [
+            "",
+            "2024-05-01",
+            "2024-05-01T10:20:30",
+            "2024-13-01T10:20:30Z",
+            "2024-02-30T10:20:30Z",
+            "2024-05-01T24:00:00Z",
+            "2024-05-01T10:20:30.Z",
+            "2024-05-01T10:20:30+0200",
+            "1714558830",
+        ] {
+            assert_eq!(parse_rfc3339(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod date;
 pub mod ro_cell;
 pub mod tui;
 pub use ro_cell::RoCell;